@@ -9,8 +9,10 @@ use crate::{
     prompts::prompt::generate_content_prompt,
     search::*,
     slash_command::{
-        current_file_command, file_command, prompt_command, SlashCommandCleanup,
-        SlashCommandCompletionProvider, SlashCommandLine, SlashCommandRegistry,
+        current_file_command, diagnostics_command, fetch_command, file_command, git_diff_command,
+        prompt_command, search_command, selection_command, symbol_command, tabs_command,
+        terminal_command, SlashCommand, SlashCommandCleanup, SlashCommandCompletionProvider,
+        SlashCommandLine, SlashCommandProgress, SlashCommandRegistry,
     },
     ApplyEdit, Assist, CompletionProvider, CycleMessageRole, InlineAssist, LanguageModel,
     LanguageModelRequest, LanguageModelRequestMessage, MessageId, MessageMetadata, MessageStatus,
@@ -36,7 +38,7 @@ use fs::Fs;
 use futures::StreamExt;
 use gpui::{
     canvas, div, point, relative, rems, uniform_list, Action, AnyElement, AnyView, AppContext,
-    AsyncAppContext, AsyncWindowContext, AvailableSpace, ClipboardItem, Context, Empty, Entity,
+    AsyncAppContext, AsyncWindowContext, AvailableSpace, ClipboardItem, Context, Entity,
     EventEmitter, FocusHandle, FocusableView, FontStyle, FontWeight, HighlightStyle,
     InteractiveElement, IntoElement, Model, ModelContext, ParentElement, Pixels, Render,
     SharedString, StatefulInteractiveElement, Styled, Subscription, Task, TextStyle,
@@ -212,13 +214,36 @@ impl AssistantPanel {
                     slash_command_registry.register_command(file_command::FileSlashCommand::new(
                         workspace.project().clone(),
                     ));
+                    slash_command_registry.register_alias("f", "file");
                     slash_command_registry.register_command(
                         prompt_command::PromptSlashCommand::new(prompt_library.clone()),
                     );
+                    slash_command_registry.register_command(
+                        diagnostics_command::DiagnosticsSlashCommand::new(
+                            workspace.project().clone(),
+                        ),
+                    );
+                    slash_command_registry.register_command(
+                        symbol_command::SymbolSlashCommand::new(workspace.project().clone()),
+                    );
+                    slash_command_registry.register_command(
+                        search_command::SearchSlashCommand::new(workspace.project().clone()),
+                    );
+                    slash_command_registry.register_command(fetch_command::FetchSlashCommand);
+                    slash_command_registry.register_command(
+                        git_diff_command::GitDiffSlashCommand::new(workspace.project().clone()),
+                    );
                     if let Some(window) = window {
                         slash_command_registry.register_command(
                             current_file_command::CurrentFileSlashCommand::new(window),
                         );
+                        slash_command_registry.register_command(
+                            selection_command::SelectionSlashCommand::new(window),
+                        );
+                        slash_command_registry
+                            .register_command(tabs_command::TabsSlashCommand::new(window));
+                        slash_command_registry
+                            .register_command(terminal_command::TerminalSlashCommand::new(window));
                     }
 
                     Self {
@@ -1459,7 +1484,11 @@ enum ConversationEvent {
     StreamedCompletion,
     SlashCommandsChanged,
     SlashCommandOutputAdded(Range<language::Anchor>),
-    SlashCommandOutputRemoved(Range<language::Anchor>),
+    SlashCommandOutputRemoved {
+        range: Range<language::Anchor>,
+        /// Whether the same command is about to insert new output in its place.
+        rerun: bool,
+    },
 }
 
 #[derive(Default)]
@@ -1836,6 +1865,7 @@ impl Conversation {
                     let line_end_offset = offset + line.len();
                     if let Some(call) = SlashCommandLine::parse(line) {
                         let mut unchanged_call = None;
+                        let mut rerun_with = None;
                         while let Some(old_call) = old_calls.peek() {
                             match old_call.source_range.start.to_offset(&buffer).cmp(&offset) {
                                 Ordering::Greater => break,
@@ -1846,10 +1876,18 @@ impl Conversation {
                                 {
                                     unchanged_call = old_calls.next();
                                 }
-                                _ => {
+                                ordering => {
                                     changed = true;
-                                    let old_call = old_calls.next().unwrap();
-                                    this.slash_command_call_removed(old_call, cx);
+                                    let mut old_call = old_calls.next().unwrap();
+                                    let rerun = ordering == Ordering::Equal
+                                        && old_call.should_rerun
+                                        && Self::slash_command_matches(&old_call, &call, line);
+                                    // A command being rerun in place may have asked
+                                    // to be replaced by another one.
+                                    if rerun {
+                                        rerun_with = old_call.rerun_with.take();
+                                    }
+                                    this.slash_command_call_removed(old_call, rerun, cx);
                                 }
                             }
                         }
@@ -1857,108 +1895,178 @@ impl Conversation {
                         let name = &line[call.name];
                         if let Some(call) = unchanged_call {
                             new_calls.push(call);
-                        } else if let Some(command) = this.slash_command_registry.command(name) {
+                        } else if let Some(command) =
+                            rerun_with.or_else(|| this.slash_command_registry.command(name))
+                        {
                             changed = true;
                             let name = name.to_string();
                             let source_range =
                                 buffer.anchor_after(offset)..buffer.anchor_before(line_end_offset);
 
-                            let argument = call.argument.map(|range| &line[range]);
-                            let invocation = command.run(
-                                argument,
-                                this.lsp_adapter_delegate
-                                    .clone()
-                                    .expect("no LspAdapterDelegate present when invoking command"),
-                                cx,
-                            );
+                            let arguments = call
+                                .arguments
+                                .iter()
+                                .filter(|range| !range.is_empty())
+                                .map(|range| &line[range.clone()])
+                                .collect::<Vec<_>>();
+                            if command.requires_argument() && arguments.is_empty() {
+                                new_calls.push(SlashCommandCall {
+                                    name,
+                                    arguments: Vec::new(),
+                                    source_range,
+                                    output_range: None,
+                                    should_rerun: false,
+                                    rerun_with: None,
+                                    error: Some("this command needs an argument".into()),
+                                    progress: None,
+                                    _invalidate: Task::ready(()),
+                                    _progress: Task::ready(()),
+                                    _command_cleanup: SlashCommandCleanup::default(),
+                                });
+                            } else {
+                                let (progress, mut progress_rx) = SlashCommandProgress::new();
+                                let invocation = command.run_with_progress(
+                                    &arguments,
+                                    this.lsp_adapter_delegate.clone().expect(
+                                        "no LspAdapterDelegate present when invoking command",
+                                    ),
+                                    progress,
+                                    cx,
+                                );
 
-                            new_calls.push(SlashCommandCall {
-                                name,
-                                argument: argument.map(|s| s.to_string()),
-                                source_range: source_range.clone(),
-                                output_range: None,
-                                should_rerun: false,
-                                _invalidate: cx.spawn(|this, mut cx| {
-                                    let source_range = source_range.clone();
-                                    let invalidated = invocation.invalidated;
-                                    async move {
-                                        if invalidated.await.is_ok() {
-                                            _ = this.update(&mut cx, |this, cx| {
-                                                let buffer = this.buffer.read(cx);
-                                                let call_ix = this
-                                                    .slash_command_calls
-                                                    .binary_search_by(|probe| {
-                                                        probe
-                                                            .source_range
-                                                            .start
-                                                            .cmp(&source_range.start, buffer)
-                                                    });
-                                                if let Ok(call_ix) = call_ix {
-                                                    this.slash_command_calls[call_ix]
-                                                        .should_rerun = true;
-                                                    this.reparse_slash_command_calls(cx);
+                                new_calls.push(SlashCommandCall {
+                                    name,
+                                    arguments: arguments.iter().map(|s| s.to_string()).collect(),
+                                    source_range: source_range.clone(),
+                                    output_range: None,
+                                    should_rerun: false,
+                                    rerun_with: None,
+                                    _invalidate: cx.spawn(|this, mut cx| {
+                                        let source_range = source_range.clone();
+                                        let invalidated = invocation.invalidated;
+                                        async move {
+                                            if let Ok(invalidation) = invalidated.await {
+                                                _ = this.update(&mut cx, |this, cx| {
+                                                    let buffer = this.buffer.read(cx);
+                                                    let call_ix = this
+                                                        .slash_command_calls
+                                                        .binary_search_by(|probe| {
+                                                            probe
+                                                                .source_range
+                                                                .start
+                                                                .cmp(&source_range.start, buffer)
+                                                        });
+                                                    if let Ok(call_ix) = call_ix {
+                                                        let call =
+                                                            &mut this.slash_command_calls[call_ix];
+                                                        call.should_rerun = true;
+                                                        call.rerun_with = invalidation.rerun_with;
+                                                        this.reparse_slash_command_calls(cx);
+                                                    }
+                                                });
+                                            }
+                                        }
+                                    }),
+                                    error: None,
+                                    progress: None,
+                                    _progress: cx.spawn(|this, mut cx| {
+                                        let source_range = source_range.clone();
+                                        async move {
+                                            while let Some(message) = progress_rx.next().await {
+                                                let updated = this.update(&mut cx, |this, cx| {
+                                                    let buffer = this.buffer.read(cx);
+                                                    let call_ix = this
+                                                        .slash_command_calls
+                                                        .binary_search_by(|probe| {
+                                                            probe
+                                                                .source_range
+                                                                .start
+                                                                .cmp(&source_range.start, buffer)
+                                                        });
+                                                    if let Ok(call_ix) = call_ix {
+                                                        this.slash_command_calls[call_ix]
+                                                            .progress = Some(message.into());
+                                                        cx.emit(
+                                                            ConversationEvent::SlashCommandsChanged,
+                                                        );
+                                                    }
+                                                });
+                                                if updated.is_err() {
+                                                    break;
                                                 }
-                                            });
+                                            }
                                         }
-                                    }
-                                }),
-                                _command_cleanup: invocation.cleanup,
-                            });
-
-                            cx.spawn(|this, mut cx| async move {
-                                let output = invocation.output.await;
-                                this.update(&mut cx, |this, cx| {
-                                    let output_range = this.buffer.update(cx, |buffer, cx| {
-                                        let call_ix = this
-                                            .slash_command_calls
-                                            .binary_search_by(|probe| {
+                                    }),
+                                    _command_cleanup: invocation.cleanup,
+                                });
+
+                                cx.spawn(|this, mut cx| async move {
+                                    let output = invocation.output.await;
+                                    this.update(&mut cx, |this, cx| {
+                                        let buffer = this.buffer.read(cx);
+                                        if let Ok(call_ix) =
+                                            this.slash_command_calls.binary_search_by(|probe| {
                                                 probe
                                                     .source_range
                                                     .start
                                                     .cmp(&source_range.start, buffer)
                                             })
-                                            .ok()?;
-
-                                        let mut output = output.log_err()?;
-                                        output.truncate(output.trim_end().len());
-
-                                        let source_end = source_range.end.to_offset(buffer);
-                                        let output_start = source_end + '\n'.len_utf8();
-                                        let output_end = output_start + output.len();
-
-                                        if buffer
-                                            .chars_at(source_end)
-                                            .next()
-                                            .map_or(false, |c| c != '\n')
                                         {
-                                            output.push('\n');
+                                            this.slash_command_calls[call_ix].progress.take();
                                         }
 
-                                        buffer.edit(
-                                            [
-                                                (source_end..source_end, "\n".to_string()),
-                                                (source_end..source_end, output),
-                                            ],
-                                            None,
-                                            cx,
-                                        );
-
-                                        let output_start = buffer.anchor_after(output_start);
-                                        let output_end = buffer.anchor_before(output_end);
-                                        this.slash_command_calls[call_ix].output_range =
-                                            Some(output_start..output_end);
-                                        Some(source_range.end..output_end)
-                                    });
-                                    if let Some(output_range) = output_range {
-                                        cx.emit(ConversationEvent::SlashCommandOutputAdded(
-                                            output_range,
-                                        ));
+                                        let output_range = this.buffer.update(cx, |buffer, cx| {
+                                            let call_ix = this
+                                                .slash_command_calls
+                                                .binary_search_by(|probe| {
+                                                    probe
+                                                        .source_range
+                                                        .start
+                                                        .cmp(&source_range.start, buffer)
+                                                })
+                                                .ok()?;
+
+                                            let mut output = output.log_err()?;
+                                            output.truncate(output.trim_end().len());
+
+                                            let source_end = source_range.end.to_offset(buffer);
+                                            let output_start = source_end + '\n'.len_utf8();
+                                            let output_end = output_start + output.len();
+
+                                            if buffer
+                                                .chars_at(source_end)
+                                                .next()
+                                                .map_or(false, |c| c != '\n')
+                                            {
+                                                output.push('\n');
+                                            }
+
+                                            buffer.edit(
+                                                [
+                                                    (source_end..source_end, "\n".to_string()),
+                                                    (source_end..source_end, output),
+                                                ],
+                                                None,
+                                                cx,
+                                            );
+
+                                            let output_start = buffer.anchor_after(output_start);
+                                            let output_end = buffer.anchor_before(output_end);
+                                            this.slash_command_calls[call_ix].output_range =
+                                                Some(output_start..output_end);
+                                            Some(source_range.end..output_end)
+                                        });
+                                        if let Some(output_range) = output_range {
+                                            cx.emit(ConversationEvent::SlashCommandOutputAdded(
+                                                output_range,
+                                            ));
+                                        }
                                         cx.emit(ConversationEvent::SlashCommandsChanged);
-                                    }
+                                    })
+                                    .ok();
                                 })
-                                .ok();
-                            })
-                            .detach();
+                                .detach();
+                            }
                         }
                     }
                     offset = lines.offset();
@@ -1966,7 +2074,7 @@ impl Conversation {
 
                 for old_call in old_calls {
                     changed = true;
-                    this.slash_command_call_removed(old_call, cx);
+                    this.slash_command_call_removed(old_call, false, cx);
                 }
 
                 if changed {
@@ -1986,11 +2094,7 @@ impl Conversation {
         new_text: &str,
         buffer: &BufferSnapshot,
     ) -> bool {
-        if old_call.name != new_text[new_call.name.clone()] {
-            return false;
-        }
-
-        if old_call.argument.as_deref() != new_call.argument.clone().map(|range| &new_text[range]) {
+        if !Self::slash_command_matches(old_call, new_call, new_text) {
             return false;
         }
 
@@ -2019,9 +2123,32 @@ impl Conversation {
         true
     }
 
+    /// Returns whether the call has the same name and arguments as the parsed line.
+    fn slash_command_matches(
+        old_call: &SlashCommandCall,
+        new_call: &SlashCommandLine,
+        new_text: &str,
+    ) -> bool {
+        if old_call.name != new_text[new_call.name.clone()] {
+            return false;
+        }
+
+        let new_arguments = new_call
+            .arguments
+            .iter()
+            .filter(|range| !range.is_empty())
+            .map(|range| &new_text[range.clone()]);
+        old_call
+            .arguments
+            .iter()
+            .map(String::as_str)
+            .eq(new_arguments)
+    }
+
     fn slash_command_call_removed(
         &self,
         old_call: SlashCommandCall,
+        rerun: bool,
         cx: &mut ModelContext<Conversation>,
     ) {
         if let Some(output_range) = old_call.output_range {
@@ -2032,9 +2159,10 @@ impl Conversation {
                     cx,
                 );
             });
-            cx.emit(ConversationEvent::SlashCommandOutputRemoved(
-                old_call.source_range.end..output_range.end,
-            ))
+            cx.emit(ConversationEvent::SlashCommandOutputRemoved {
+                range: old_call.source_range.end..output_range.end,
+                rerun,
+            })
         }
     }
 
@@ -2706,9 +2834,16 @@ struct SlashCommandCall {
     source_range: Range<language::Anchor>,
     output_range: Option<Range<language::Anchor>>,
     name: String,
-    argument: Option<String>,
+    arguments: Vec<String>,
     should_rerun: bool,
+    /// The command to run in place of the registered one when rerunning.
+    rerun_with: Option<Arc<dyn SlashCommand>>,
+    /// A validation error that prevented the command from running.
+    error: Option<SharedString>,
+    /// The latest progress message reported while the command is running.
+    progress: Option<SharedString>,
     _invalidate: Task<()>,
+    _progress: Task<()>,
     _command_cleanup: SlashCommandCleanup,
 }
 
@@ -2734,6 +2869,9 @@ struct ConversationEditor {
     editor: View<Editor>,
     flap_ids: HashMap<Range<language::Anchor>, FlapId>,
     blocks: HashSet<BlockId>,
+    slash_command_status_blocks: HashSet<BlockId>,
+    /// The start of each slash command output that the user has expanded.
+    expanded_slash_command_outputs: Arc<Mutex<Vec<language::Anchor>>>,
     scroll_position: Option<ScrollPosition>,
     _subscriptions: Vec<Subscription>,
 }
@@ -2798,6 +2936,8 @@ impl ConversationEditor {
             blocks: Default::default(),
             scroll_position: None,
             flap_ids: Default::default(),
+            slash_command_status_blocks: Default::default(),
+            expanded_slash_command_outputs: Default::default(),
             fs,
             workspace: workspace.downgrade(),
             _subscriptions,
@@ -2942,6 +3082,7 @@ impl ConversationEditor {
                     let excerpt_id = *buffer.as_singleton().unwrap().0;
                     let conversation = self.conversation.read(cx);
                     let colors = cx.theme().colors();
+                    let status_colors = cx.theme().status();
                     let highlighted_rows = conversation
                         .slash_command_calls
                         .iter()
@@ -2954,10 +3095,43 @@ impl ConversationEditor {
                             };
                             let start = buffer.anchor_in_excerpt(excerpt_id, start).unwrap();
                             let end = buffer.anchor_in_excerpt(excerpt_id, end).unwrap();
-                            (
-                                start..=end,
-                                Some(colors.editor_document_highlight_read_background),
-                            )
+                            let color = if call.error.is_some() {
+                                status_colors.error_background
+                            } else {
+                                colors.editor_document_highlight_read_background
+                            };
+                            (start..=end, Some(color))
+                        })
+                        .collect::<Vec<_>>();
+
+                    let status_blocks = conversation
+                        .slash_command_calls
+                        .iter()
+                        .filter_map(|call| {
+                            let (status, color) = if let Some(error) = call.error.clone() {
+                                (error, Color::Error)
+                            } else {
+                                (call.progress.clone()?, Color::Muted)
+                            };
+                            Some(BlockProperties {
+                                position: buffer
+                                    .anchor_in_excerpt(excerpt_id, call.source_range.end)
+                                    .unwrap(),
+                                height: 1,
+                                style: BlockStyle::Flex,
+                                render: Box::new(move |cx| {
+                                    h_flex()
+                                        .pl(cx.gutter_dimensions.width
+                                            + cx.gutter_dimensions.margin)
+                                        .child(
+                                            Label::new(status.clone())
+                                                .size(LabelSize::Small)
+                                                .color(color),
+                                        )
+                                        .into_any_element()
+                                }),
+                                disposition: BlockDisposition::Below,
+                            })
                         })
                         .collect::<Vec<_>>();
 
@@ -2965,25 +3139,91 @@ impl ConversationEditor {
                     for (range, color) in highlighted_rows {
                         editor.highlight_rows::<SlashCommandCall>(range, color, false, cx);
                     }
+
+                    let old_status_blocks = mem::take(&mut self.slash_command_status_blocks);
+                    editor.remove_blocks(old_status_blocks, None, cx);
+                    let ids = editor.insert_blocks(status_blocks, None, cx);
+                    self.slash_command_status_blocks = HashSet::from_iter(ids);
                 });
             }
             ConversationEvent::SlashCommandOutputAdded(range) => {
                 self.editor.update(cx, |editor, cx| {
                     let buffer = editor.buffer().read(cx).snapshot(cx);
-                    let excerpt_id = *buffer.as_singleton().unwrap().0;
+                    let (&excerpt_id, _, text_buffer) = buffer.as_singleton().unwrap();
                     let start = buffer.anchor_in_excerpt(excerpt_id, range.start).unwrap();
                     let end = buffer.anchor_in_excerpt(excerpt_id, range.end).unwrap();
                     let buffer_row = MultiBufferRow(start.to_point(&buffer).row);
+                    let line_count = end.to_point(&buffer).row - buffer_row.0;
+
+                    // Outputs start out folded, unless the output of a previous run
+                    // of the same command had been expanded.
+                    let output_start = range.start;
+                    let was_expanded = {
+                        let mut expanded_outputs = self.expanded_slash_command_outputs.lock();
+                        let offset = output_start.to_offset(text_buffer);
+                        let expanded_count = expanded_outputs.len();
+                        expanded_outputs.retain(|start| start.to_offset(text_buffer) != offset);
+                        expanded_outputs.len() < expanded_count
+                    };
 
+                    let expanded_outputs = self.expanded_slash_command_outputs.clone();
+                    let editor_handle = cx.view().downgrade();
                     let flap_id = editor
                         .insert_flaps(
                             [Flap::new(
                                 start..end,
                                 FoldPlaceholder {
-                                    render: Arc::new(|_, _, _| Empty.into_any()),
+                                    render: Arc::new({
+                                        let expanded_outputs = expanded_outputs.clone();
+                                        move |fold_id, fold_range, _cx| {
+                                            let editor = editor_handle.clone();
+                                            let expanded_outputs = expanded_outputs.clone();
+                                            ButtonLike::new(fold_id)
+                                                .style(ButtonStyle::Filled)
+                                                .layer(ElevationIndex::ElevatedSurface)
+                                                .child(
+                                                    Label::new(format!("{} lines", line_count))
+                                                        .size(LabelSize::Small)
+                                                        .color(Color::Muted),
+                                                )
+                                                .on_click(move |_, cx| {
+                                                    set_slash_command_output_expanded(
+                                                        &expanded_outputs,
+                                                        output_start,
+                                                        true,
+                                                    );
+                                                    editor
+                                                        .update(cx, |editor, cx| {
+                                                            editor.unfold_ranges(
+                                                                [fold_range.start..fold_range.end],
+                                                                true,
+                                                                false,
+                                                                cx,
+                                                            );
+                                                        })
+                                                        .ok();
+                                                })
+                                                .into_any_element()
+                                        }
+                                    }),
                                     constrain_width: false,
                                 },
-                                render_slash_command_output_toggle,
+                                move |row, is_folded, fold: ToggleFold, cx| {
+                                    let expanded_outputs = expanded_outputs.clone();
+                                    render_slash_command_output_toggle(
+                                        row,
+                                        is_folded,
+                                        Arc::new(move |fold_output, cx| {
+                                            set_slash_command_output_expanded(
+                                                &expanded_outputs,
+                                                output_start,
+                                                !fold_output,
+                                            );
+                                            fold(fold_output, cx);
+                                        }),
+                                        cx,
+                                    )
+                                },
                                 render_slash_command_output_trailer,
                             )],
                             cx,
@@ -2992,15 +3232,30 @@ impl ConversationEditor {
                         .next()
                         .unwrap();
                     self.flap_ids.insert(range.clone(), flap_id);
-                    editor.fold_at(&FoldAt { buffer_row }, cx);
+                    if was_expanded {
+                        self.expanded_slash_command_outputs
+                            .lock()
+                            .push(output_start);
+                    } else {
+                        editor.fold_at(&FoldAt { buffer_row }, cx);
+                    }
                 });
             }
-            ConversationEvent::SlashCommandOutputRemoved(range) => {
+            ConversationEvent::SlashCommandOutputRemoved { range, rerun } => {
                 if let Some(flap_id) = self.flap_ids.remove(range) {
                     self.editor.update(cx, |editor, cx| {
                         editor.remove_flaps([flap_id], cx);
                     });
                 }
+                // Only a rerun's output inherits the expanded state, so forget
+                // it once the call is gone for good.
+                if !rerun {
+                    set_slash_command_output_expanded(
+                        &self.expanded_slash_command_outputs,
+                        range.start,
+                        false,
+                    );
+                }
             }
         }
     }
@@ -3901,6 +4156,18 @@ fn render_slash_command_output_toggle(
     .into_any_element()
 }
 
+fn set_slash_command_output_expanded(
+    expanded_outputs: &Mutex<Vec<language::Anchor>>,
+    output_start: language::Anchor,
+    expanded: bool,
+) {
+    let mut expanded_outputs = expanded_outputs.lock();
+    expanded_outputs.retain(|start| *start != output_start);
+    if expanded {
+        expanded_outputs.push(output_start);
+    }
+}
+
 fn render_slash_command_output_trailer(
     _row: MultiBufferRow,
     _is_folded: bool,
@@ -4362,7 +4629,7 @@ mod tests {
                     ConversationEvent::SlashCommandOutputAdded(range) => {
                         ranges.borrow_mut().insert(range.clone());
                     }
-                    ConversationEvent::SlashCommandOutputRemoved(range) => {
+                    ConversationEvent::SlashCommandOutputRemoved { range, .. } => {
                         ranges.borrow_mut().remove(range);
                     }
                     _ => {}
@@ -4545,6 +4812,21 @@ mod tests {
             }]
         );
 
+        // Commands that require an argument don't run without one.
+        buffer.update(cx, |buffer, cx| {
+            buffer.edit([(buffer.len()..buffer.len(), "/file")], None, cx);
+        });
+        cx.executor().advance_clock(SLASH_COMMAND_DEBOUNCE);
+        conversation.read_with(cx, |conversation, _| {
+            let call = conversation.slash_command_calls.last().unwrap();
+            assert_eq!(call.name, "file");
+            assert_eq!(
+                call.error.as_deref(),
+                Some("this command needs an argument")
+            );
+            assert!(call.output_range.is_none());
+        });
+
         #[track_caller]
         fn assert_text_and_output_ranges(
             buffer: &Model<Buffer>,
@@ -60,6 +60,11 @@ impl PromptLibrary {
             .collect()
     }
 
+    /// Returns a number that changes whenever the set of prompts changes.
+    pub fn version(&self) -> usize {
+        self.state.read().version
+    }
+
     pub fn first_prompt_id(&self) -> Option<PromptId> {
         let state = self.state.read();
         state.prompts.keys().next().cloned()
@@ -114,7 +119,9 @@ impl PromptLibrary {
         // let current_prompts = self.all_prompt_contents().clone();
 
         // For now, we'll just clear the prompts and reload them all
-        self.state.get_mut().prompts.clear();
+        let state = self.state.get_mut();
+        state.prompts.clear();
+        state.version += 1;
 
         let mut prompt_paths = fs.read_dir(&PROMPTS_DIR).await?;
 
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use collections::HashSet;
 use editor::{CompletionProvider, Editor};
 use fuzzy::{match_strings, StringMatchCandidate};
 use gpui::{AppContext, Model, Task, ViewContext};
@@ -14,12 +15,21 @@ use std::{
 };
 
 pub use assistant_slash_command::{
-    SlashCommand, SlashCommandCleanup, SlashCommandInvocation, SlashCommandRegistry,
+    SlashCommand, SlashCommandCleanup, SlashCommandInvalidation, SlashCommandInvocation,
+    SlashCommandProgress, SlashCommandRegistry,
 };
 
 pub mod current_file_command;
+pub mod diagnostics_command;
+pub mod fetch_command;
 pub mod file_command;
+pub mod git_diff_command;
 pub mod prompt_command;
+pub mod search_command;
+pub mod selection_command;
+pub mod symbol_command;
+pub mod tabs_command;
+pub mod terminal_command;
 
 pub(crate) struct SlashCommandCompletionProvider {
     commands: Arc<SlashCommandRegistry>,
@@ -29,8 +39,12 @@ pub(crate) struct SlashCommandCompletionProvider {
 pub(crate) struct SlashCommandLine {
     /// The range within the line containing the command name.
     pub name: Range<usize>,
-    /// The range within the line containing the command argument.
-    pub argument: Option<Range<usize>>,
+    /// The ranges within the line containing the command arguments.
+    ///
+    /// When the line ends with whitespace after the command name or an
+    /// argument, the last range is empty, representing an argument that has
+    /// not been typed yet.
+    pub arguments: Vec<Range<usize>>,
 }
 
 impl SlashCommandCompletionProvider {
@@ -47,10 +61,12 @@ impl SlashCommandCompletionProvider {
         range: Range<Anchor>,
         cx: &mut AppContext,
     ) -> Task<Result<Vec<project::Completion>>> {
+        // Aliases are matched too, but complete to the name of the command they refer to.
         let candidates = self
             .commands
             .command_names()
             .into_iter()
+            .chain(self.commands.aliases().into_iter().map(|(alias, _)| alias))
             .enumerate()
             .map(|(ix, def)| StringMatchCandidate {
                 id: ix,
@@ -62,21 +78,35 @@ impl SlashCommandCompletionProvider {
         let command_name = command_name.to_string();
         let executor = cx.background_executor().clone();
         executor.clone().spawn(async move {
-            let matches = match_strings(
+            let mut matches = match_strings(
                 &candidates,
                 &command_name,
-                true,
+                false,
                 usize::MAX,
                 &Default::default(),
                 executor,
             )
             .await;
+            // Rank names that start with the query above other fuzzy matches,
+            // keeping the fuzzy score order within each group.
+            let lowercase_command_name = command_name.to_lowercase();
+            matches.sort_by_key(|mat| {
+                !mat.string
+                    .to_lowercase()
+                    .starts_with(&lowercase_command_name)
+            });
 
+            let mut completed_names = HashSet::default();
             Ok(matches
                 .into_iter()
                 .filter_map(|mat| {
                     let command = commands.command(&mat.string)?;
-                    let mut new_text = mat.string.clone();
+                    let name = command.name();
+                    if !completed_names.insert(name.clone()) {
+                        return None;
+                    }
+
+                    let mut new_text = name.clone();
                     if command.requires_argument() {
                         new_text.push(' ');
                     }
@@ -85,7 +115,7 @@ impl SlashCommandCompletionProvider {
                         old_range: range.clone(),
                         documentation: Some(Documentation::SingleLine(command.description())),
                         new_text,
-                        label: CodeLabel::plain(mat.string, None),
+                        label: CodeLabel::plain(name, None),
                         server_id: LanguageServerId(0),
                         lsp_completion: Default::default(),
                     })
@@ -98,6 +128,7 @@ impl SlashCommandCompletionProvider {
         &self,
         command_name: &str,
         argument: String,
+        argument_index: usize,
         range: Range<Anchor>,
         cx: &mut AppContext,
     ) -> Task<Result<Vec<project::Completion>>> {
@@ -107,15 +138,27 @@ impl SlashCommandCompletionProvider {
         *flag = new_cancel_flag.clone();
 
         if let Some(command) = self.commands.command(command_name) {
-            let completions = command.complete_argument(argument, new_cancel_flag.clone(), cx);
+            let completions =
+                command.complete_argument(argument, argument_index, new_cancel_flag.clone(), cx);
             cx.background_executor().spawn(async move {
+                let completions = completions.await?;
+                // A newer completion request has superseded this one.
+                if new_cancel_flag.load(SeqCst) {
+                    return Ok(Vec::new());
+                }
+
                 Ok(completions
-                    .await?
                     .into_iter()
                     .map(|arg| project::Completion {
                         old_range: range.clone(),
                         label: CodeLabel::plain(arg.clone(), None),
-                        new_text: arg.clone(),
+                        // Arguments containing whitespace must be quoted so that they
+                        // aren't split into several arguments.
+                        new_text: if arg.contains(char::is_whitespace) {
+                            format!("\"{arg}\"")
+                        } else {
+                            arg.clone()
+                        },
                         documentation: None,
                         server_id: LanguageServerId(0),
                         lsp_completion: Default::default(),
@@ -139,15 +182,21 @@ impl CompletionProvider for SlashCommandCompletionProvider {
         let task = buffer.update(cx, |buffer, cx| {
             let position = buffer_position.to_point(buffer);
             let line_start = Point::new(position.row, 0);
-            let mut lines = buffer.text_for_range(line_start..position).lines();
-            let line = lines.next()?;
-            let call = SlashCommandLine::parse(line)?;
+            let line_end = Point::new(position.row, buffer.line_len(position.row));
+            let line = buffer
+                .text_for_range(line_start..line_end)
+                .collect::<String>();
+            let cursor = position.column as usize;
+            let call = SlashCommandLine::parse(&line[..cursor])?;
 
             let name = &line[call.name.clone()];
-            if let Some(argument) = call.argument {
-                let start = buffer.anchor_after(Point::new(position.row, argument.start as u32));
+            if let Some(argument) = call.arguments.last() {
+                let (argument_index, replaced_range) = argument_replacement_range(&line, cursor)?;
+                let start =
+                    buffer.anchor_after(Point::new(position.row, replaced_range.start as u32));
+                let end = buffer.anchor_before(Point::new(position.row, replaced_range.end as u32));
                 let argument = line[argument.clone()].to_string();
-                Some(self.complete_command_argument(name, argument, start..buffer_position, cx))
+                Some(self.complete_command_argument(name, argument, argument_index, start..end, cx))
             } else {
                 let start = buffer.anchor_after(Point::new(position.row, call.name.start as u32));
                 Some(self.complete_command_name(name, start..buffer_position, cx))
@@ -189,10 +238,22 @@ impl CompletionProvider for SlashCommandCompletionProvider {
         let position = position.to_point(buffer);
         let line_start = Point::new(position.row, 0);
         let mut lines = buffer.text_for_range(line_start..position).lines();
-        if let Some(line) = lines.next() {
-            SlashCommandLine::parse(line).is_some()
-        } else {
-            false
+        let Some(line) = lines.next() else {
+            return false;
+        };
+        let Some(call) = SlashCommandLine::parse(line) else {
+            return false;
+        };
+
+        // Whitespace alone only triggers argument completion when the command
+        // is still waiting for its required first argument.
+        match call.arguments.as_slice() {
+            [argument] if argument.is_empty() => self
+                .commands
+                .command(&line[call.name.clone()])
+                .map_or(false, |command| command.requires_argument()),
+            [.., argument] => !argument.is_empty(),
+            [] => true,
         }
     }
 }
@@ -200,22 +261,48 @@ impl CompletionProvider for SlashCommandCompletionProvider {
 impl SlashCommandLine {
     pub(crate) fn parse(line: &str) -> Option<Self> {
         let mut call: Option<Self> = None;
+        let mut quoted = false;
+        let mut argument_closed = false;
         let mut ix = 0;
         for c in line.chars() {
             let next_ix = ix + c.len_utf8();
             if let Some(call) = &mut call {
-                // The command arguments start at the first non-whitespace character
-                // after the command name, and continue until the end of the line.
-                if let Some(argument) = &mut call.argument {
-                    if (*argument).is_empty() && c.is_whitespace() {
-                        argument.start = next_ix;
+                // Arguments are separated by whitespace, and each argument starts at
+                // the first non-whitespace character after the previous one.
+                //
+                // An argument starting with a double quote ends at the closing
+                // quote instead, and excludes the quotes themselves. If the quote
+                // is never closed, the argument continues until the end of the line.
+                if let Some(argument) = call.arguments.last_mut() {
+                    if quoted {
+                        if c == '"' {
+                            quoted = false;
+                            argument_closed = true;
+                        } else {
+                            argument.end = next_ix;
+                        }
+                    } else if c.is_whitespace() {
+                        if argument.is_empty() && !argument_closed {
+                            argument.start = next_ix;
+                            argument.end = next_ix;
+                        } else {
+                            argument_closed = false;
+                            call.arguments.push(next_ix..next_ix);
+                        }
+                    }
+                    // Characters directly following a closing quote are ignored.
+                    else if !argument_closed {
+                        if argument.is_empty() && c == '"' {
+                            quoted = true;
+                            argument.start = next_ix;
+                        }
+                        argument.end = next_ix;
                     }
-                    argument.end = next_ix;
                 }
                 // The command name ends at the first whitespace character.
                 else if !call.name.is_empty() {
                     if c.is_whitespace() {
-                        call.argument = Some(next_ix..next_ix);
+                        call.arguments.push(next_ix..next_ix);
                     } else {
                         call.name.end = next_ix;
                     }
@@ -231,7 +318,7 @@ impl SlashCommandLine {
             else if c == '/' {
                 call = Some(SlashCommandLine {
                     name: next_ix..next_ix,
-                    argument: None,
+                    arguments: Vec::new(),
                 });
             }
             // The line can't contain anything before the slash except for whitespace.
@@ -243,3 +330,197 @@ impl SlashCommandLine {
         call
     }
 }
+
+/// Returns the index of the argument being typed at `cursor`, along with the
+/// range of `line` that completing it should replace.
+///
+/// The range covers the whole argument, including any text after the cursor
+/// and its quotes, so that the completion replaces it entirely.
+fn argument_replacement_range(line: &str, cursor: usize) -> Option<(usize, Range<usize>)> {
+    let call = SlashCommandLine::parse(&line[..cursor])?;
+    let argument = call.arguments.last()?;
+    let argument_index = call.arguments.len() - 1;
+
+    let mut range = argument.clone();
+    let full_argument =
+        SlashCommandLine::parse(line).and_then(|call| call.arguments.get(argument_index).cloned());
+    if let Some(full_argument) = full_argument {
+        if full_argument.start == argument.start {
+            range.end = full_argument.end;
+        }
+    }
+
+    // Replace the quotes of a quoted argument too, since completions are
+    // re-quoted as needed.
+    if line[..range.start].ends_with('"') {
+        range.start -= 1;
+        if line[range.end..].starts_with('"') {
+            range.end += 1;
+        }
+    }
+    Some((argument_index, range))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::oneshot;
+    use gpui::TestAppContext;
+    use language::LspAdapterDelegate;
+
+    #[test]
+    fn test_argument_replacement_range() {
+        // The text after the cursor is replaced along with the partial query.
+        assert_replaced("/file src/li|b.rs", Some((0, "src/lib.rs")));
+        assert_replaced("/file src/lib.rs|", Some((0, "src/lib.rs")));
+        assert_replaced("/file src/lib.rs 1|0-20", Some((1, "10-20")));
+        assert_replaced("/file |src/lib.rs", Some((0, "src/lib.rs")));
+        assert_replaced("/file src/lib.rs |", Some((1, "")));
+
+        // Quoted arguments are replaced along with their quotes.
+        assert_replaced("/prompt \"my pr|ompt\" next", Some((0, "\"my prompt\"")));
+        assert_replaced("/prompt \"my pr|", Some((0, "\"my pr")));
+
+        // There's no argument to replace while typing the command name.
+        assert_replaced("/fi|", None);
+
+        /// Asserts which argument is replaced when completing at the `|` in
+        /// `marked_line`, and the text it replaces.
+        #[track_caller]
+        fn assert_replaced(marked_line: &str, expected: Option<(usize, &str)>) {
+            let cursor = marked_line.find('|').unwrap();
+            let line = marked_line.replacen('|', "", 1);
+            let actual = argument_replacement_range(&line, cursor)
+                .map(|(index, range)| (index, &line[range]));
+            assert_eq!(actual, expected, "{:?}", marked_line);
+        }
+    }
+
+    #[gpui::test]
+    async fn test_complete_command_name(cx: &mut TestAppContext) {
+        let registry = SlashCommandRegistry::new();
+        for name in ["current-file", "diagnostics", "file", "prompt", "tabs"] {
+            registry.register_command(TestSlashCommand(name));
+        }
+        let provider = SlashCommandCompletionProvider::new(registry);
+
+        let completions = cx
+            .update(|cx| provider.complete_command_name("fi", Anchor::MIN..Anchor::MAX, cx))
+            .await
+            .unwrap();
+        assert_eq!(completions[0].new_text, "file");
+        assert!(completions
+            .iter()
+            .any(|completion| completion.new_text == "current-file"));
+
+        // Matching is case-insensitive.
+        let completions = cx
+            .update(|cx| provider.complete_command_name("File", Anchor::MIN..Anchor::MAX, cx))
+            .await
+            .unwrap();
+        assert_eq!(completions[0].new_text, "file");
+
+        // Prefix matches rank above other fuzzy matches.
+        let completions = cx
+            .update(|cx| provider.complete_command_name("t", Anchor::MIN..Anchor::MAX, cx))
+            .await
+            .unwrap();
+        assert_eq!(completions[0].new_text, "tabs");
+    }
+
+    struct TestSlashCommand(&'static str);
+
+    impl SlashCommand for TestSlashCommand {
+        fn name(&self) -> String {
+            self.0.into()
+        }
+
+        fn description(&self) -> String {
+            String::new()
+        }
+
+        fn complete_argument(
+            &self,
+            _query: String,
+            _argument_index: usize,
+            _cancel: Arc<AtomicBool>,
+            _cx: &mut AppContext,
+        ) -> Task<Result<Vec<String>>> {
+            Task::ready(Ok(Vec::new()))
+        }
+
+        fn requires_argument(&self) -> bool {
+            false
+        }
+
+        fn run(
+            self: Arc<Self>,
+            _arguments: &[&str],
+            _delegate: Arc<dyn LspAdapterDelegate>,
+            _cx: &mut AppContext,
+        ) -> SlashCommandInvocation {
+            SlashCommandInvocation {
+                output: Task::ready(Ok(String::new())),
+                invalidated: oneshot::channel().1,
+                cleanup: SlashCommandCleanup::default(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_slash_command_line() {
+        assert_eq!(parse("hello"), None);
+        assert_eq!(parse("/"), Some(("", vec![])));
+        assert_eq!(parse("/file"), Some(("file", vec![])));
+        assert_eq!(parse("  /file "), Some(("file", vec![""])));
+
+        // Unquoted arguments are separated by whitespace.
+        assert_eq!(
+            parse("/file src/lib.rs"),
+            Some(("file", vec!["src/lib.rs"]))
+        );
+        assert_eq!(
+            parse("/file   src/lib.rs  10-20"),
+            Some(("file", vec!["src/lib.rs", "10-20"]))
+        );
+        assert_eq!(
+            parse("/file src/lib.rs "),
+            Some(("file", vec!["src/lib.rs", ""]))
+        );
+        assert_eq!(
+            parse("/file src/\"quoted\".rs"),
+            Some(("file", vec!["src/\"quoted\".rs"]))
+        );
+
+        // Quoted arguments end at the closing quote.
+        assert_eq!(
+            parse("/file \"my folder/name.rs\""),
+            Some(("file", vec!["my folder/name.rs"]))
+        );
+        assert_eq!(
+            parse("/file \"my folder/name.rs\" trailing"),
+            Some(("file", vec!["my folder/name.rs", "trailing"]))
+        );
+        assert_eq!(
+            parse("/file one \"two three\" \"\" four"),
+            Some(("file", vec!["one", "two three", "", "four"]))
+        );
+
+        // Unterminated quotes extend to the end of the line.
+        assert_eq!(
+            parse("/file one \"my folder/na"),
+            Some(("file", vec!["one", "my folder/na"]))
+        );
+
+        fn parse(line: &str) -> Option<(&str, Vec<&str>)> {
+            let call = SlashCommandLine::parse(line)?;
+            Some((
+                &line[call.name],
+                call.arguments
+                    .into_iter()
+                    .map(|argument| &line[argument])
+                    .collect(),
+            ))
+        }
+    }
+}
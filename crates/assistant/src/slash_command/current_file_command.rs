@@ -9,7 +9,7 @@ use gpui::{AppContext, Entity, Subscription, Task, WindowHandle};
 use language::LspAdapterDelegate;
 use workspace::{Event as WorkspaceEvent, Workspace};
 
-use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvocation};
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvalidation, SlashCommandInvocation};
 
 pub(crate) struct CurrentFileSlashCommand {
     workspace: WindowHandle<Workspace>,
@@ -33,6 +33,7 @@ impl SlashCommand for CurrentFileSlashCommand {
     fn complete_argument(
         &self,
         _query: String,
+        _argument_index: usize,
         _cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
         _cx: &mut AppContext,
     ) -> Task<Result<Vec<String>>> {
@@ -45,7 +46,7 @@ impl SlashCommand for CurrentFileSlashCommand {
 
     fn run(
         self: Arc<Self>,
-        _argument: Option<&str>,
+        _arguments: &[&str],
         _delegate: Arc<dyn LspAdapterDelegate>,
         cx: &mut AppContext,
     ) -> SlashCommandInvocation {
@@ -90,7 +91,7 @@ impl SlashCommand for CurrentFileSlashCommand {
                         | WorkspaceEvent::PaneAdded(_)
                         | WorkspaceEvent::PaneRemoved => {
                             if let Some(invalidate_tx) = invalidate_tx.take() {
-                                _ = invalidate_tx.send(());
+                                _ = invalidate_tx.send(SlashCommandInvalidation::default());
                             }
                         }
                         _ => {}
@@ -102,7 +103,7 @@ impl SlashCommand for CurrentFileSlashCommand {
                     let invalidate_tx = invalidate_tx.clone();
                     cx.window_context().observe(&buffer, move |_buffer, _cx| {
                         if let Some(invalidate_tx) = invalidate_tx.take() {
-                            _ = invalidate_tx.send(());
+                            _ = invalidate_tx.send(SlashCommandInvalidation::default());
                         }
                     })
                 });
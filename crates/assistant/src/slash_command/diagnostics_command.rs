@@ -0,0 +1,177 @@
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvalidation, SlashCommandInvocation};
+use anyhow::{anyhow, Result};
+use futures::channel::oneshot;
+use fuzzy::StringMatchCandidate;
+use gpui::{AppContext, Model, Task};
+use language::{DiagnosticSeverity, LspAdapterDelegate, Point};
+use project::{Project, ProjectPath};
+use std::{
+    cell::Cell,
+    fmt::Write,
+    path::Path,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+};
+
+pub(crate) struct DiagnosticsSlashCommand {
+    project: Model<Project>,
+}
+
+impl DiagnosticsSlashCommand {
+    pub fn new(project: Model<Project>) -> Self {
+        Self { project }
+    }
+
+    fn paths_with_diagnostics(&self, cx: &AppContext) -> Vec<ProjectPath> {
+        let mut paths = self
+            .project
+            .read(cx)
+            .diagnostic_summaries(false, cx)
+            .filter(|(_, _, summary)| summary.error_count > 0 || summary.warning_count > 0)
+            .map(|(path, _, _)| path)
+            .collect::<Vec<_>>();
+        paths.dedup();
+        paths
+    }
+}
+
+impl SlashCommand for DiagnosticsSlashCommand {
+    fn name(&self) -> String {
+        "diagnostics".into()
+    }
+
+    fn description(&self) -> String {
+        "insert project diagnostics".into()
+    }
+
+    fn requires_argument(&self) -> bool {
+        false
+    }
+
+    fn complete_argument(
+        &self,
+        query: String,
+        argument_index: usize,
+        cancellation_flag: Arc<AtomicBool>,
+        cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        if argument_index > 0 || cancellation_flag.load(SeqCst) {
+            return Task::ready(Ok(Vec::new()));
+        }
+
+        let candidates = self
+            .paths_with_diagnostics(cx)
+            .into_iter()
+            .enumerate()
+            .map(|(ix, path)| StringMatchCandidate::new(ix, path.path.to_string_lossy().into()))
+            .collect::<Vec<_>>();
+        let executor = cx.background_executor().clone();
+        cx.background_executor().spawn(async move {
+            let matches = fuzzy::match_strings(
+                &candidates,
+                &query,
+                false,
+                100,
+                &cancellation_flag,
+                executor,
+            )
+            .await;
+            Ok(matches
+                .into_iter()
+                .map(|mat| candidates[mat.candidate_id].string.clone())
+                .collect())
+        })
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[&str],
+        _delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let mut paths = self.paths_with_diagnostics(cx);
+        if let Some(argument) = arguments.first() {
+            let path = Path::new(argument);
+            paths.retain(|project_path| project_path.path.as_ref() == path);
+        }
+
+        let (invalidate_tx, invalidate_rx) = oneshot::channel();
+        let invalidate_tx = Rc::new(Cell::new(Some(invalidate_tx)));
+        let subscription = cx.subscribe(&self.project, move |_, event: &project::Event, _| {
+            if let project::Event::DiagnosticsUpdated { .. } = event {
+                if let Some(invalidate_tx) = invalidate_tx.take() {
+                    _ = invalidate_tx.send(SlashCommandInvalidation::default());
+                }
+            }
+        });
+
+        let buffers = self.project.update(cx, |project, cx| {
+            paths
+                .into_iter()
+                .map(|path| (path.path.clone(), project.open_buffer(path, cx)))
+                .collect::<Vec<_>>()
+        });
+
+        let output = cx.spawn(|cx| async move {
+            let mut lines = String::new();
+            let mut error_count = 0;
+            let mut warning_count = 0;
+            for (path, buffer) in buffers {
+                let snapshot = buffer
+                    .await?
+                    .read_with(&cx, |buffer, _| buffer.snapshot())?;
+                for entry in snapshot.diagnostics_in_range::<_, Point>(0..snapshot.len(), false) {
+                    if !entry.diagnostic.is_primary {
+                        continue;
+                    }
+                    let severity = match entry.diagnostic.severity {
+                        DiagnosticSeverity::ERROR => {
+                            error_count += 1;
+                            "error"
+                        }
+                        DiagnosticSeverity::WARNING => {
+                            warning_count += 1;
+                            "warning"
+                        }
+                        _ => continue,
+                    };
+                    writeln!(
+                        lines,
+                        "{}:{}:{}: {}: {}",
+                        path.to_string_lossy(),
+                        entry.range.start.row + 1,
+                        entry.range.start.column + 1,
+                        severity,
+                        entry.diagnostic.message
+                    )?;
+                }
+            }
+
+            if error_count + warning_count == 0 {
+                return Err(anyhow!("no diagnostics found"));
+            }
+
+            let mut output = String::new();
+            writeln!(
+                output,
+                "{} diagnostics ({} errors, {} warnings)",
+                error_count + warning_count,
+                error_count,
+                warning_count
+            )?;
+            output.push_str("```\n");
+            output.push_str(&lines);
+            output.push_str("```");
+            Ok(output)
+        });
+
+        SlashCommandInvocation {
+            output,
+            invalidated: invalidate_rx,
+            cleanup: SlashCommandCleanup::new(move || drop(subscription)),
+        }
+    }
+}
@@ -0,0 +1,366 @@
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvocation};
+use anyhow::{anyhow, bail, Context, Result};
+use futures::{channel::oneshot, AsyncReadExt};
+use gpui::{AppContext, Task};
+use http::{AsyncBody, Url};
+use language::LspAdapterDelegate;
+use std::sync::{atomic::AtomicBool, Arc};
+
+/// Elements whose contents are never displayed as text.
+const HIDDEN_ELEMENTS: &[&str] = &["head", "script", "style", "noscript", "template", "svg"];
+
+/// Elements that start on a new line.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+pub(crate) struct FetchSlashCommand;
+
+impl SlashCommand for FetchSlashCommand {
+    fn name(&self) -> String {
+        "fetch".into()
+    }
+
+    fn description(&self) -> String {
+        "insert the text of a web page".into()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        &self,
+        _query: String,
+        _argument_index: usize,
+        _cancel: Arc<AtomicBool>,
+        _cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        Task::ready(Ok(Vec::new()))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[&str],
+        delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let url = match arguments.first().map(|argument| parse_url(argument)) {
+            Some(Ok(url)) => url,
+            Some(Err(error)) => {
+                return SlashCommandInvocation {
+                    output: Task::ready(Err(error)),
+                    invalidated: oneshot::channel().1,
+                    cleanup: SlashCommandCleanup::default(),
+                }
+            }
+            None => {
+                return SlashCommandInvocation {
+                    output: Task::ready(Err(anyhow!("missing URL"))),
+                    invalidated: oneshot::channel().1,
+                    cleanup: SlashCommandCleanup::default(),
+                }
+            }
+        };
+
+        let http_client = delegate.http_client();
+        let output = cx.background_executor().spawn(async move {
+            let mut response = http_client
+                .get(url.as_str(), AsyncBody::empty(), true)
+                .await
+                .with_context(|| format!("failed to fetch {}", url))?;
+            if !response.status().is_success() {
+                bail!("failed to fetch {}: {}", url, response.status());
+            }
+
+            let is_html = response
+                .headers()
+                .get("content-type")
+                .and_then(|content_type| content_type.to_str().ok())
+                .map_or(true, |content_type| content_type.contains("html"));
+            let mut body = String::new();
+            response
+                .body_mut()
+                .read_to_string(&mut body)
+                .await
+                .with_context(|| format!("failed to read response from {}", url))?;
+
+            let text = if is_html { html_to_text(&body) } else { body };
+            if text.trim().is_empty() {
+                bail!("no text found at {}", url);
+            }
+
+            let mut output = String::new();
+            output.push_str("```");
+            output.push_str(url.host_str().unwrap_or_default());
+            output.push('\n');
+            output.push_str(text.trim_end());
+            output.push_str("\n```");
+            Ok(output)
+        });
+
+        SlashCommandInvocation {
+            output,
+            invalidated: oneshot::channel().1,
+            cleanup: SlashCommandCleanup::default(),
+        }
+    }
+}
+
+/// Parses the argument as a URL, assuming `https` when no scheme is given.
+fn parse_url(argument: &str) -> Result<Url> {
+    let url = if argument.starts_with("http://") || argument.starts_with("https://") {
+        Url::parse(argument)
+    } else {
+        Url::parse(&format!("https://{}", argument))
+    };
+    url.with_context(|| format!("invalid URL {:?}", argument))
+}
+
+/// Extracts the readable text from an HTML document.
+///
+/// Hidden elements are dropped, block elements start new lines, and
+/// character references are decoded. Whitespace is collapsed, except inside
+/// `<pre>` elements, whose text is kept verbatim.
+fn html_to_text(html: &str) -> String {
+    // Runs of text, alternating between flowing and preformatted text.
+    let mut segments = vec![String::new()];
+    let mut pre_depth = 0;
+    let mut rest = html;
+    while let Some(tag_start) = rest.find('<') {
+        push_text(
+            segments.last_mut().unwrap(),
+            &rest[..tag_start],
+            pre_depth > 0,
+        );
+        rest = &rest[tag_start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map_or("", |end| &comment[end + "-->".len()..]);
+            continue;
+        }
+
+        let Some(tag_end) = rest.find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let is_self_closing = tag.ends_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if name == "pre" && !is_self_closing {
+            if is_closing {
+                if pre_depth > 0 {
+                    pre_depth -= 1;
+                    if pre_depth == 0 {
+                        segments.push(String::new());
+                    }
+                }
+            } else {
+                if pre_depth == 0 {
+                    segments.push(String::new());
+                }
+                pre_depth += 1;
+            }
+        } else if !is_closing && !is_self_closing && HIDDEN_ELEMENTS.contains(&name.as_str()) {
+            let closing_tag = format!("</{}", name);
+            rest = rest
+                .to_ascii_lowercase()
+                .find(&closing_tag)
+                .map_or("", |end| &rest[end..]);
+        } else if pre_depth > 0 {
+            if name == "br" {
+                segments.last_mut().unwrap().push('\n');
+            }
+        } else if BLOCK_ELEMENTS.contains(&name.as_str()) {
+            segments.last_mut().unwrap().push('\n');
+        }
+    }
+    push_text(segments.last_mut().unwrap(), rest, pre_depth > 0);
+
+    // Collapse runs of whitespace within lines, and runs of blank lines, in
+    // flowing text. Even-indexed segments hold flowing text.
+    let mut output = String::new();
+    let mut pending_blank_line = false;
+    for (ix, segment) in segments.iter().enumerate() {
+        if ix % 2 == 1 {
+            // A newline directly after `<pre>` isn't part of its content.
+            let text = segment.strip_prefix('\n').unwrap_or(segment).trim_end();
+            if !text.is_empty() {
+                push_line(&mut output, text, &mut pending_blank_line);
+            }
+            continue;
+        }
+
+        for line in segment.lines() {
+            let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+            if line.is_empty() {
+                pending_blank_line = !output.is_empty();
+                continue;
+            }
+            push_line(&mut output, &line, &mut pending_blank_line);
+        }
+    }
+    output
+}
+
+fn push_line(output: &mut String, line: &str, pending_blank_line: &mut bool) {
+    if !output.is_empty() {
+        output.push('\n');
+        if *pending_blank_line {
+            output.push('\n');
+        }
+    }
+    *pending_blank_line = false;
+    output.push_str(line);
+}
+
+fn push_text(output: &mut String, mut text: &str, preformatted: bool) {
+    let push_plain_text = |output: &mut String, text: &str| {
+        if preformatted {
+            output.push_str(text);
+        } else {
+            push_whitespace_as_spaces(output, text);
+        }
+    };
+
+    while let Some(ix) = text.find('&') {
+        push_plain_text(output, &text[..ix]);
+        text = &text[ix..];
+
+        let reference = text
+            .find(';')
+            .filter(|end| *end <= "&#x10FFFF".len())
+            .and_then(|end| Some((decode_character_reference(&text[1..end])?, end)));
+        if let Some((character, end)) = reference {
+            output.push(character);
+            text = &text[end + 1..];
+        } else {
+            output.push('&');
+            text = &text[1..];
+        }
+    }
+    push_plain_text(output, text);
+}
+
+fn push_whitespace_as_spaces(output: &mut String, text: &str) {
+    output.extend(
+        text.chars()
+            .map(|c| if c.is_whitespace() { ' ' } else { c }),
+    );
+}
+
+fn decode_character_reference(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = if let Some(hex) = code.strip_prefix(['x', 'X']) {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                code.parse().ok()?
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text(
+                "<html><head><title>Title</title></head>\
+                 <body><h1>Heading</h1>\n<p>Some   <b>bold</b>\ntext.</p>\
+                 <script>let x = 1 < 2;</script><!-- comment -->\
+                 <p>Fish &amp; chips &lt;3 &#39;yum&#x27;</p></body></html>"
+            ),
+            "Heading\n\nSome bold text.\n\nFish & chips <3 'yum'"
+        );
+        assert_eq!(html_to_text("a<br>b<br/>c"), "a\nb\nc");
+        assert_eq!(html_to_text("AT&T &bogus; &"), "AT&T &bogus; &");
+        assert_eq!(html_to_text("<STYLE>p {}</STYLE>text"), "text");
+    }
+
+    #[test]
+    fn test_html_to_text_preformatted() {
+        assert_eq!(
+            html_to_text(
+                "<p>Example:</p><pre>\n<code>fn main() {\n    println!(&quot;hi&quot;);\n\n    \
+                 <span>return</span>;\n}</code>\n</pre><p>After   the   code.</p>"
+            ),
+            "Example:\nfn main() {\n    println!(\"hi\");\n\n    return;\n}\nAfter the code."
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_self_closing_hidden_element() {
+        assert_eq!(
+            html_to_text("<p>Before<svg viewBox=\"0 0 1 1\" /></p><p>After</p>"),
+            "Before\nAfter"
+        );
+    }
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            parse_url("zed.dev/docs").unwrap().as_str(),
+            "https://zed.dev/docs"
+        );
+        assert_eq!(
+            parse_url("http://example.com").unwrap().host_str(),
+            Some("example.com")
+        );
+        assert!(parse_url("https://").is_err());
+    }
+}
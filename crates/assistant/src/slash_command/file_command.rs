@@ -6,8 +6,13 @@ use gpui::{AppContext, Model, Task};
 use language::LspAdapterDelegate;
 use project::{PathMatchCandidateSet, Project};
 use std::{
+    fmt::Write,
+    ops::RangeInclusive,
     path::Path,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
 };
 
 pub(crate) struct FileSlashCommand {
@@ -78,13 +83,22 @@ impl SlashCommand for FileSlashCommand {
     fn complete_argument(
         &self,
         query: String,
+        argument_index: usize,
         cancellation_flag: Arc<AtomicBool>,
         cx: &mut AppContext,
     ) -> gpui::Task<Result<Vec<String>>> {
-        let paths = self.search_paths(query, cancellation_flag, cx);
+        if argument_index > 0 {
+            return Task::ready(Ok(Vec::new()));
+        }
+
+        let paths = self.search_paths(query, cancellation_flag.clone(), cx);
         cx.background_executor().spawn(async move {
+            let paths = paths.await;
+            if cancellation_flag.load(SeqCst) {
+                return Ok(Vec::new());
+            }
+
             Ok(paths
-                .await
                 .into_iter()
                 .map(|path_match| {
                     format!(
@@ -99,12 +113,12 @@ impl SlashCommand for FileSlashCommand {
 
     fn run(
         self: Arc<Self>,
-        argument: Option<&str>,
+        arguments: &[&str],
         _delegate: Arc<dyn LspAdapterDelegate>,
         cx: &mut AppContext,
     ) -> SlashCommandInvocation {
         let project = self.project.read(cx);
-        let Some(argument) = argument else {
+        let Some(argument) = arguments.first().copied() else {
             return SlashCommandInvocation {
                 output: Task::ready(Err(anyhow::anyhow!("missing path"))),
                 invalidated: oneshot::channel().1,
@@ -112,7 +126,8 @@ impl SlashCommand for FileSlashCommand {
             };
         };
 
-        let path = Path::new(argument);
+        let (path, line_range) = parse_line_range(argument);
+        let path = Path::new(path);
         let abs_path = project.worktrees().find_map(|worktree| {
             let worktree = worktree.read(cx);
             worktree.entry_for_path(path)?;
@@ -128,12 +143,19 @@ impl SlashCommand for FileSlashCommand {
         };
 
         let fs = project.fs().clone();
-        let argument = argument.to_string();
+        let path = path.to_string_lossy().to_string();
         let output = cx.background_executor().spawn(async move {
-            let content = fs.load(&abs_path).await?;
-            let mut output = String::with_capacity(argument.len() + content.len() + 9);
+            let mut content = fs.load(&abs_path).await?;
+            let mut header = path;
+            if let Some(line_range) = line_range {
+                let (text, line_range) = lines_in_range(&content, line_range);
+                content = text;
+                write!(header, ":{}-{}", line_range.start(), line_range.end())?;
+            }
+
+            let mut output = String::with_capacity(header.len() + content.len() + 9);
             output.push_str("```");
-            output.push_str(&argument);
+            output.push_str(&header);
             output.push('\n');
             output.push_str(&content);
             if !output.ends_with('\n') {
@@ -149,3 +171,78 @@ impl SlashCommand for FileSlashCommand {
         }
     }
 }
+
+/// Splits a trailing `:start-end` (or `:line`) suffix off of the given argument,
+/// returning the path and the 1-based, inclusive line range.
+fn parse_line_range(argument: &str) -> (&str, Option<RangeInclusive<u32>>) {
+    let Some((path, range)) = argument.rsplit_once(':') else {
+        return (argument, None);
+    };
+
+    let range = if let Some((start, end)) = range.split_once('-') {
+        start.parse().ok().zip(end.parse().ok())
+    } else {
+        range.parse().ok().map(|line| (line, line))
+    };
+
+    match range {
+        Some((start, end)) if start <= end => (path, Some(start..=end)),
+        _ => (argument, None),
+    }
+}
+
+/// Returns the lines of `content` within the given 1-based, inclusive line
+/// range, along with that range clamped to the lines that exist.
+fn lines_in_range(content: &str, range: RangeInclusive<u32>) -> (String, RangeInclusive<u32>) {
+    let line_count = content.lines().count().max(1) as u32;
+    let start = (*range.start()).clamp(1, line_count);
+    let end = (*range.end()).clamp(start, line_count);
+    let text = content
+        .lines()
+        .skip(start as usize - 1)
+        .take((end - start + 1) as usize)
+        .collect::<Vec<_>>()
+        .join("\n");
+    (text, start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_range() {
+        assert_eq!(parse_line_range("src/lib.rs"), ("src/lib.rs", None));
+        assert_eq!(
+            parse_line_range("src/lib.rs:10-40"),
+            ("src/lib.rs", Some(10..=40))
+        );
+        assert_eq!(
+            parse_line_range("src/lib.rs:7"),
+            ("src/lib.rs", Some(7..=7))
+        );
+        assert_eq!(
+            parse_line_range("src/lib.rs:40-10"),
+            ("src/lib.rs:40-10", None)
+        );
+        assert_eq!(parse_line_range("src/a:b.rs"), ("src/a:b.rs", None));
+    }
+
+    #[test]
+    fn test_lines_in_range() {
+        let content = "one\ntwo\nthree\nfour\n";
+        assert_eq!(
+            lines_in_range(content, 2..=3),
+            ("two\nthree".to_string(), 2..=3)
+        );
+        assert_eq!(
+            lines_in_range(content, 3..=100),
+            ("three\nfour".to_string(), 3..=4)
+        );
+        assert_eq!(
+            lines_in_range(content, 50..=100),
+            ("four".to_string(), 4..=4)
+        );
+        assert_eq!(lines_in_range(content, 0..=1), ("one".to_string(), 1..=1));
+    }
+}
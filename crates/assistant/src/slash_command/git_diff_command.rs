@@ -0,0 +1,135 @@
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvocation};
+use anyhow::{anyhow, bail, Context, Result};
+use futures::channel::oneshot;
+use gpui::{AppContext, Model, Task};
+use language::LspAdapterDelegate;
+use project::Project;
+use std::{
+    fmt::Write,
+    path::Path,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+const STAGED_FLAG: &str = "--staged";
+
+pub(crate) struct GitDiffSlashCommand {
+    project: Model<Project>,
+}
+
+impl GitDiffSlashCommand {
+    pub fn new(project: Model<Project>) -> Self {
+        Self { project }
+    }
+}
+
+impl SlashCommand for GitDiffSlashCommand {
+    fn name(&self) -> String {
+        "git-diff".into()
+    }
+
+    fn description(&self) -> String {
+        "insert the git diff of the project".into()
+    }
+
+    fn requires_argument(&self) -> bool {
+        false
+    }
+
+    fn complete_argument(
+        &self,
+        query: String,
+        _argument_index: usize,
+        _cancel: Arc<AtomicBool>,
+        _cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        let completions = if STAGED_FLAG.starts_with(&query) {
+            vec![STAGED_FLAG.to_string()]
+        } else {
+            Vec::new()
+        };
+        Task::ready(Ok(completions))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[&str],
+        _delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let staged = arguments.contains(&STAGED_FLAG);
+        let path = arguments
+            .iter()
+            .find(|argument| **argument != STAGED_FLAG)
+            .map(|path| path.to_string());
+
+        // Run git in the worktree containing the given path, or in the first
+        // worktree if no path was given.
+        let project = self.project.read(cx);
+        let worktree_root = project.visible_worktrees(cx).find_map(|worktree| {
+            let worktree = worktree.read(cx);
+            if let Some(path) = &path {
+                worktree.entry_for_path(Path::new(path))?;
+            }
+            Some(worktree.abs_path())
+        });
+        let Some(worktree_root) = worktree_root else {
+            return SlashCommandInvocation {
+                output: Task::ready(Err(match path {
+                    Some(path) => anyhow!("no worktree contains {:?}", path),
+                    None => anyhow!("no worktree found"),
+                })),
+                invalidated: oneshot::channel().1,
+                cleanup: SlashCommandCleanup::default(),
+            };
+        };
+
+        let output = cx.background_executor().spawn(async move {
+            let mut command = smol::process::Command::new("git");
+            command.current_dir(worktree_root.as_ref()).arg("diff");
+            if staged {
+                command.arg(STAGED_FLAG);
+            }
+            if let Some(path) = &path {
+                command.arg("--").arg(path);
+            }
+
+            let output = command.output().await.context("failed to run git")?;
+            if !output.status.success() {
+                bail!(
+                    "git diff failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+
+            let diff = String::from_utf8_lossy(&output.stdout);
+            let file_count = diff
+                .lines()
+                .filter(|line| line.starts_with("diff --git "))
+                .count();
+            if file_count == 0 {
+                bail!("no changes found");
+            }
+
+            let mut text = String::new();
+            writeln!(
+                text,
+                "{} {} changed",
+                file_count,
+                if file_count == 1 { "file" } else { "files" }
+            )?;
+            text.push_str("```diff\n");
+            text.push_str(&diff);
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text.push_str("```");
+            Ok(text)
+        });
+
+        SlashCommandInvocation {
+            output,
+            invalidated: oneshot::channel().1,
+            cleanup: SlashCommandCleanup::default(),
+        }
+    }
+}
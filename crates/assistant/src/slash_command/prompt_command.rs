@@ -5,15 +5,42 @@ use futures::channel::oneshot;
 use fuzzy::StringMatchCandidate;
 use gpui::{AppContext, Task};
 use language::LspAdapterDelegate;
+use parking_lot::Mutex;
 use std::sync::{atomic::AtomicBool, Arc};
 
 pub(crate) struct PromptSlashCommand {
     library: Arc<PromptLibrary>,
+    /// Completion candidates, along with the library version they were built from.
+    candidates: Mutex<Option<(usize, Arc<Vec<StringMatchCandidate>>)>>,
 }
 
 impl PromptSlashCommand {
     pub fn new(library: Arc<PromptLibrary>) -> Self {
-        Self { library }
+        Self {
+            library,
+            candidates: Mutex::default(),
+        }
+    }
+
+    fn candidates(&self) -> Arc<Vec<StringMatchCandidate>> {
+        let version = self.library.version();
+        let mut cache = self.candidates.lock();
+        if let Some((cached_version, candidates)) = cache.as_ref() {
+            if *cached_version == version {
+                return candidates.clone();
+            }
+        }
+
+        let candidates = Arc::new(
+            self.library
+                .prompts()
+                .into_iter()
+                .enumerate()
+                .map(|(ix, prompt)| StringMatchCandidate::new(ix, prompt.1.title().to_string()))
+                .collect::<Vec<_>>(),
+        );
+        *cache = Some((version, candidates.clone()));
+        candidates
     }
 }
 
@@ -33,18 +60,17 @@ impl SlashCommand for PromptSlashCommand {
     fn complete_argument(
         &self,
         query: String,
+        argument_index: usize,
         cancellation_flag: Arc<AtomicBool>,
         cx: &mut AppContext,
     ) -> Task<Result<Vec<String>>> {
-        let library = self.library.clone();
+        if argument_index > 0 {
+            return Task::ready(Ok(Vec::new()));
+        }
+
+        let candidates = self.candidates();
         let executor = cx.background_executor().clone();
         cx.background_executor().spawn(async move {
-            let candidates = library
-                .prompts()
-                .into_iter()
-                .enumerate()
-                .map(|(ix, prompt)| StringMatchCandidate::new(ix, prompt.1.title().to_string()))
-                .collect::<Vec<_>>();
             let matches = fuzzy::match_strings(
                 &candidates,
                 &query,
@@ -63,20 +89,22 @@ impl SlashCommand for PromptSlashCommand {
 
     fn run(
         self: Arc<Self>,
-        title: Option<&str>,
+        arguments: &[&str],
         _delegate: Arc<dyn LspAdapterDelegate>,
         cx: &mut AppContext,
     ) -> SlashCommandInvocation {
-        let Some(title) = title else {
+        if arguments.is_empty() {
             return SlashCommandInvocation {
                 output: Task::ready(Err(anyhow!("missing prompt name"))),
                 invalidated: oneshot::channel().1,
                 cleanup: SlashCommandCleanup::default(),
             };
-        };
+        }
 
+        // Prompt titles may contain spaces, so an unquoted title is split
+        // across several arguments.
         let library = self.library.clone();
-        let title = title.to_string();
+        let title = arguments.join(" ");
         let output = cx.background_executor().spawn(async move {
             let prompt = library
                 .prompts()
@@ -0,0 +1,155 @@
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvocation, SlashCommandProgress};
+use anyhow::{anyhow, Result};
+use futures::channel::oneshot;
+use gpui::{AppContext, Model, Task};
+use language::{LspAdapterDelegate, Point, ToPoint as _};
+use project::{search::SearchQuery, Project, SearchResult};
+use std::{
+    collections::BTreeSet,
+    fmt::Write,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+pub(crate) struct SearchSlashCommand {
+    project: Model<Project>,
+}
+
+impl SearchSlashCommand {
+    pub fn new(project: Model<Project>) -> Self {
+        Self { project }
+    }
+}
+
+impl SlashCommand for SearchSlashCommand {
+    fn name(&self) -> String {
+        "search".into()
+    }
+
+    fn description(&self) -> String {
+        "insert project search results".into()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        &self,
+        _query: String,
+        _argument_index: usize,
+        _cancel: Arc<AtomicBool>,
+        _cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        Task::ready(Ok(Vec::new()))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[&str],
+        delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let (progress, _) = SlashCommandProgress::new();
+        self.run_with_progress(arguments, delegate, progress, cx)
+    }
+
+    fn run_with_progress(
+        self: Arc<Self>,
+        arguments: &[&str],
+        _delegate: Arc<dyn LspAdapterDelegate>,
+        progress: SlashCommandProgress,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        // A leading `--regex` argument switches to a regular expression search.
+        let (is_regex, terms) = match arguments {
+            ["--regex", terms @ ..] => (true, terms),
+            terms => (false, terms),
+        };
+        let query = terms.join(" ");
+        if query.is_empty() {
+            return SlashCommandInvocation {
+                output: Task::ready(Err(anyhow!("missing search query"))),
+                invalidated: oneshot::channel().1,
+                cleanup: SlashCommandCleanup::default(),
+            };
+        }
+
+        let query = if is_regex {
+            SearchQuery::regex(query, false, false, false, Vec::new(), Vec::new())
+        } else {
+            SearchQuery::text(query, false, false, false, Vec::new(), Vec::new())
+        };
+        let query = match query {
+            Ok(query) => query,
+            Err(error) => {
+                return SlashCommandInvocation {
+                    output: Task::ready(Err(error)),
+                    invalidated: oneshot::channel().1,
+                    cleanup: SlashCommandCleanup::default(),
+                }
+            }
+        };
+
+        let results = self
+            .project
+            .update(cx, |project, cx| project.search(query, cx));
+        let output = cx.spawn(|cx| async move {
+            progress.report("searching…");
+            let mut lines = String::new();
+            let mut match_count = 0;
+            let mut file_count = 0;
+            let mut limit_reached = false;
+            while let Ok(result) = results.recv().await {
+                let (buffer, ranges) = match result {
+                    SearchResult::Buffer { buffer, ranges } => (buffer, ranges),
+                    SearchResult::LimitReached => {
+                        limit_reached = true;
+                        break;
+                    }
+                };
+                let snapshot = buffer.read_with(&cx, |buffer, _| buffer.snapshot())?;
+                let path = snapshot
+                    .file()
+                    .map(|file| file.path().to_string_lossy().to_string())
+                    .unwrap_or_else(|| "untitled".to_string());
+
+                let rows = ranges
+                    .iter()
+                    .map(|range| range.start.to_point(&snapshot).row)
+                    .collect::<BTreeSet<_>>();
+                match_count += ranges.len();
+                file_count += 1;
+                for row in rows {
+                    let line = snapshot
+                        .text_for_range(Point::new(row, 0)..Point::new(row, snapshot.line_len(row)))
+                        .collect::<String>();
+                    writeln!(lines, "{}:{}: {}", path, row + 1, line.trim())?;
+                }
+                progress.report(format!(
+                    "searching… {} matches in {} files",
+                    match_count, file_count
+                ));
+            }
+
+            if match_count == 0 {
+                return Err(anyhow!("no matches found"));
+            }
+
+            let mut output = String::new();
+            write!(output, "{} matches in {} files", match_count, file_count)?;
+            if limit_reached {
+                output.push_str(" (limit reached)");
+            }
+            output.push_str("\n```\n");
+            output.push_str(&lines);
+            output.push_str("```");
+            Ok(output)
+        });
+
+        SlashCommandInvocation {
+            output,
+            invalidated: oneshot::channel().1,
+            cleanup: SlashCommandCleanup::default(),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::ops::Range;
+use std::sync::Arc;
+use std::{borrow::Cow, fmt::Write};
+
+use anyhow::{anyhow, Result};
+use editor::Editor;
+use futures::channel::oneshot;
+use gpui::{AppContext, Model, Task, WindowHandle};
+use language::{Anchor, Buffer, LspAdapterDelegate, Point, ToPoint};
+use workspace::Workspace;
+
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvalidation, SlashCommandInvocation};
+
+pub(crate) struct SelectionSlashCommand {
+    workspace: WindowHandle<Workspace>,
+}
+
+impl SelectionSlashCommand {
+    pub fn new(workspace: WindowHandle<Workspace>) -> Self {
+        Self { workspace }
+    }
+}
+
+impl SlashCommand for SelectionSlashCommand {
+    fn name(&self) -> String {
+        "selection".into()
+    }
+
+    fn description(&self) -> String {
+        "insert the active editor's selection".into()
+    }
+
+    fn complete_argument(
+        &self,
+        _query: String,
+        _argument_index: usize,
+        _cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        _cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        Task::ready(Err(anyhow!("this command does not require argument")))
+    }
+
+    fn requires_argument(&self) -> bool {
+        false
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[&str],
+        delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let selection = self.workspace.update(cx, |workspace, cx| {
+            let editor = workspace
+                .active_item(cx)
+                .and_then(|item| item.act_as::<Editor>(cx))
+                .ok_or_else(|| anyhow!("no active editor"))?;
+            let buffer = editor
+                .read(cx)
+                .buffer()
+                .read(cx)
+                .as_singleton()
+                .ok_or_else(|| anyhow!("the active editor has multiple buffers"))?;
+
+            let selection = editor.read(cx).selections.newest::<Point>(cx);
+            if selection.is_empty() {
+                return Err(anyhow!("no text selected"));
+            }
+
+            let snapshot = buffer.read(cx).snapshot();
+            let range =
+                snapshot.anchor_after(selection.start)..snapshot.anchor_before(selection.end);
+            Ok(SelectedRangeSlashCommand { buffer, range })
+        });
+
+        match selection.and_then(|selection| selection) {
+            Ok(selection) => Arc::new(selection).run(arguments, delegate, cx),
+            Err(error) => SlashCommandInvocation {
+                output: Task::ready(Err(error)),
+                invalidated: oneshot::channel().1,
+                cleanup: SlashCommandCleanup::default(),
+            },
+        }
+    }
+}
+
+/// Inserts the text of a range that was selected when `/selection` first ran.
+///
+/// The selection is re-inserted when its buffer changes, but not when the
+/// selection moves, so that the output stays put while the user keeps editing.
+struct SelectedRangeSlashCommand {
+    buffer: Model<Buffer>,
+    range: Range<Anchor>,
+}
+
+impl SlashCommand for SelectedRangeSlashCommand {
+    fn name(&self) -> String {
+        "selection".into()
+    }
+
+    fn description(&self) -> String {
+        "insert the active editor's selection".into()
+    }
+
+    fn complete_argument(
+        &self,
+        _query: String,
+        _argument_index: usize,
+        _cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        _cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        Task::ready(Err(anyhow!("this command does not require argument")))
+    }
+
+    fn requires_argument(&self) -> bool {
+        false
+    }
+
+    fn run(
+        self: Arc<Self>,
+        _arguments: &[&str],
+        _delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let (invalidate_tx, invalidate_rx) = oneshot::channel();
+        let subscription = cx.observe(&self.buffer, {
+            let this = self.clone();
+            let mut invalidate_tx = Some(invalidate_tx);
+            move |_buffer, _cx| {
+                if let Some(invalidate_tx) = invalidate_tx.take() {
+                    _ = invalidate_tx.send(SlashCommandInvalidation {
+                        rerun_with: Some(this.clone() as Arc<dyn SlashCommand>),
+                    });
+                }
+            }
+        });
+
+        let snapshot = self.buffer.read(cx).snapshot();
+        let path = snapshot.resolve_file_path(cx, true);
+        let range = self.range.start.to_point(&snapshot)..self.range.end.to_point(&snapshot);
+        let output = cx.background_executor().spawn(async move {
+            if range.is_empty() {
+                return Err(anyhow!("the selected text was deleted"));
+            }
+
+            let path = path
+                .as_ref()
+                .map(|path| path.to_string_lossy())
+                .unwrap_or_else(|| Cow::Borrowed("untitled"));
+
+            // Don't include the line after a selection that ends at the
+            // start of a line.
+            let end_row = if range.end.column == 0 && range.end.row > range.start.row {
+                range.end.row - 1
+            } else {
+                range.end.row
+            };
+
+            let mut output = String::new();
+            output.push_str("```");
+            output.push_str(&path);
+            write!(output, ":{}-{}", range.start.row + 1, end_row + 1)?;
+            output.push('\n');
+            for chunk in snapshot.text_for_range(range) {
+                output.push_str(chunk);
+            }
+            if !output.ends_with('\n') {
+                output.push('\n');
+            }
+            output.push_str("```");
+            Ok(output)
+        });
+
+        SlashCommandInvocation {
+            output,
+            invalidated: invalidate_rx,
+            cleanup: SlashCommandCleanup::new(move || drop(subscription)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fs::FakeFs;
+    use gpui::{Context as _, TestAppContext};
+    use project::{Project, ProjectLspAdapterDelegate};
+    use serde_json::json;
+    use settings::SettingsStore;
+
+    #[gpui::test]
+    async fn test_selected_range_follows_edits(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            Project::init_settings(cx);
+        });
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree("/test", json!({ "a.txt": "" })).await;
+        let project = Project::test(fs, ["/test".as_ref()], cx).await;
+        let delegate = project.update(cx, |project, cx| {
+            let worktree = project.worktrees().next().unwrap();
+            ProjectLspAdapterDelegate::new(project, &worktree, cx) as Arc<dyn LspAdapterDelegate>
+        });
+
+        let buffer = cx.new_model(|cx| Buffer::local("one\ntwo\nthree\n", cx));
+        let range = buffer.read_with(cx, |buffer, _| {
+            buffer.anchor_after(Point::new(1, 0))..buffer.anchor_before(Point::new(2, 0))
+        });
+        let command = Arc::new(SelectedRangeSlashCommand {
+            buffer: buffer.clone(),
+            range,
+        });
+
+        let invocation = cx.update(|cx| command.clone().run(&[], delegate.clone(), cx));
+        assert_eq!(
+            invocation.output.await.unwrap(),
+            "```untitled:2-2\ntwo\n```"
+        );
+
+        // Editing the buffer invalidates the output, and the rerun reads the
+        // originally selected text, even though lines were inserted before it.
+        buffer.update(cx, |buffer, cx| {
+            buffer.edit([(0..0, "zero\n"), (5..6, "W")], None, cx)
+        });
+        let invalidation = invocation.invalidated.await.unwrap();
+        let rerun_with = invalidation.rerun_with.unwrap();
+        drop(invocation.cleanup);
+
+        let invocation = cx.update(|cx| rerun_with.run(&[], delegate.clone(), cx));
+        assert_eq!(
+            invocation.output.await.unwrap(),
+            "```untitled:3-3\ntWo\n```"
+        );
+
+        // Deleting the selected text reports an error rather than inserting
+        // something else.
+        buffer.update(cx, |buffer, cx| buffer.edit([(9..13, "")], None, cx));
+        let rerun_with = invocation.invalidated.await.unwrap().rerun_with.unwrap();
+        let invocation = cx.update(|cx| rerun_with.run(&[], delegate, cx));
+        assert!(invocation.output.await.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvocation};
+use anyhow::{anyhow, Context as _, Result};
+use futures::channel::oneshot;
+use fuzzy::StringMatchCandidate;
+use gpui::{AppContext, Model, Task};
+use language::{Bias, LspAdapterDelegate, OffsetRangeExt, Point};
+use project::Project;
+use std::sync::{
+    atomic::{AtomicBool, Ordering::SeqCst},
+    Arc,
+};
+
+pub(crate) struct SymbolSlashCommand {
+    project: Model<Project>,
+}
+
+impl SymbolSlashCommand {
+    pub fn new(project: Model<Project>) -> Self {
+        Self { project }
+    }
+}
+
+impl SlashCommand for SymbolSlashCommand {
+    fn name(&self) -> String {
+        "symbol".into()
+    }
+
+    fn description(&self) -> String {
+        "insert a symbol's definition".into()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        &self,
+        query: String,
+        argument_index: usize,
+        cancellation_flag: Arc<AtomicBool>,
+        cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        if argument_index > 0 {
+            return Task::ready(Ok(Vec::new()));
+        }
+
+        let symbols = self
+            .project
+            .update(cx, |project, cx| project.symbols(&query, cx));
+        let executor = cx.background_executor().clone();
+        cx.background_executor().spawn(async move {
+            let symbols = symbols.await?;
+            if cancellation_flag.load(SeqCst) {
+                return Ok(Vec::new());
+            }
+
+            let mut names = symbols
+                .into_iter()
+                .map(|symbol| symbol.name)
+                .collect::<Vec<_>>();
+            names.sort_unstable();
+            names.dedup();
+
+            let candidates = names
+                .into_iter()
+                .enumerate()
+                .map(|(ix, name)| StringMatchCandidate::new(ix, name))
+                .collect::<Vec<_>>();
+            let matches = fuzzy::match_strings(
+                &candidates,
+                &query,
+                false,
+                100,
+                &cancellation_flag,
+                executor,
+            )
+            .await;
+            Ok(matches
+                .into_iter()
+                .map(|mat| candidates[mat.candidate_id].string.clone())
+                .collect())
+        })
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[&str],
+        _delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let Some(name) = arguments.first() else {
+            return SlashCommandInvocation {
+                output: Task::ready(Err(anyhow!("missing symbol name"))),
+                invalidated: oneshot::channel().1,
+                cleanup: SlashCommandCleanup::default(),
+            };
+        };
+
+        let project = self.project.clone();
+        let name = name.to_string();
+        let symbols = project.update(cx, |project, cx| project.symbols(&name, cx));
+        let output = cx.spawn(|mut cx| async move {
+            let symbol = symbols
+                .await?
+                .into_iter()
+                .find(|symbol| symbol.name == name)
+                .with_context(|| format!("no symbol found with name {:?}", name))?;
+            let buffer = project
+                .update(&mut cx, |project, cx| {
+                    project.open_buffer_for_symbol(&symbol, cx)
+                })?
+                .await?;
+            let snapshot = buffer.read_with(&cx, |buffer, _| buffer.snapshot())?;
+
+            let symbol_start = snapshot.clip_point_utf16(symbol.range.start, Bias::Left);
+            let symbol_end = snapshot.clip_point_utf16(symbol.range.end, Bias::Right);
+            let range = snapshot
+                .symbols_containing(symbol_start, None)
+                .and_then(|items| items.last().map(|item| item.range.to_point(&snapshot)))
+                .unwrap_or_else(|| {
+                    Point::new(symbol_start.row, 0)
+                        ..Point::new(symbol_end.row, snapshot.line_len(symbol_end.row))
+                });
+
+            let path = symbol.path.path.to_string_lossy();
+            let mut output = String::new();
+            output.push_str("```");
+            output.push_str(&path);
+            output.push('\n');
+            for chunk in snapshot.text_for_range(range) {
+                output.push_str(chunk);
+            }
+            if !output.ends_with('\n') {
+                output.push('\n');
+            }
+            output.push_str("```");
+            Ok(output)
+        });
+
+        SlashCommandInvocation {
+            output,
+            invalidated: oneshot::channel().1,
+            cleanup: SlashCommandCleanup::default(),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+use std::{borrow::Cow, cell::Cell, fmt::Write, rc::Rc};
+
+use anyhow::{anyhow, Result};
+use collections::HashSet;
+use editor::Editor;
+use futures::channel::oneshot;
+use gpui::{AppContext, Entity, Subscription, Task, WindowHandle};
+use language::LspAdapterDelegate;
+use workspace::{Event as WorkspaceEvent, Workspace};
+
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvalidation, SlashCommandInvocation};
+
+pub(crate) struct TabsSlashCommand {
+    workspace: WindowHandle<Workspace>,
+}
+
+impl TabsSlashCommand {
+    pub fn new(workspace: WindowHandle<Workspace>) -> Self {
+        Self { workspace }
+    }
+}
+
+impl SlashCommand for TabsSlashCommand {
+    fn name(&self) -> String {
+        "tabs".into()
+    }
+
+    fn description(&self) -> String {
+        "insert content of open tabs".into()
+    }
+
+    fn complete_argument(
+        &self,
+        _query: String,
+        _argument_index: usize,
+        _cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        _cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        Task::ready(Err(anyhow!("this command does not require argument")))
+    }
+
+    fn requires_argument(&self) -> bool {
+        false
+    }
+
+    fn run(
+        self: Arc<Self>,
+        _arguments: &[&str],
+        _delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let (invalidate_tx, invalidate_rx) = oneshot::channel();
+        let invalidate_tx = Rc::new(Cell::new(Some(invalidate_tx)));
+        let mut subscriptions: Vec<Subscription> = Vec::new();
+        let output = self.workspace.update(cx, |workspace, cx| {
+            let mut seen_buffers = HashSet::default();
+            let mut snapshots = Vec::new();
+            for editor in workspace.items_of_type::<Editor>(cx) {
+                let Some(buffer) = editor.read(cx).buffer().read(cx).as_singleton() else {
+                    continue;
+                };
+                if !seen_buffers.insert(buffer.entity_id()) {
+                    continue;
+                }
+
+                subscriptions.push({
+                    let invalidate_tx = invalidate_tx.clone();
+                    cx.window_context().observe(&buffer, move |_buffer, _cx| {
+                        if let Some(invalidate_tx) = invalidate_tx.take() {
+                            _ = invalidate_tx.send(SlashCommandInvalidation::default());
+                        }
+                    })
+                });
+
+                let snapshot = buffer.read(cx).snapshot();
+                let path = snapshot.resolve_file_path(cx, true);
+                snapshots.push((path, snapshot));
+            }
+
+            subscriptions.push({
+                let workspace_view = cx.view().clone();
+                let invalidate_tx = invalidate_tx.clone();
+                cx.window_context()
+                    .subscribe(&workspace_view, move |_workspace, event, _cx| match event {
+                        WorkspaceEvent::ItemAdded
+                        | WorkspaceEvent::ItemRemoved
+                        | WorkspaceEvent::PaneAdded(_)
+                        | WorkspaceEvent::PaneRemoved => {
+                            if let Some(invalidate_tx) = invalidate_tx.take() {
+                                _ = invalidate_tx.send(SlashCommandInvalidation::default());
+                            }
+                        }
+                        _ => {}
+                    })
+            });
+
+            if snapshots.is_empty() {
+                return Task::ready(Err(anyhow!("no open tabs found")));
+            }
+
+            cx.background_executor().spawn(async move {
+                let mut output = String::new();
+                writeln!(
+                    output,
+                    "{} {}",
+                    snapshots.len(),
+                    if snapshots.len() == 1 { "tab" } else { "tabs" }
+                )?;
+                for (ix, (path, snapshot)) in snapshots.into_iter().enumerate() {
+                    let path = path
+                        .as_ref()
+                        .map(|path| path.to_string_lossy())
+                        .unwrap_or_else(|| Cow::Borrowed("untitled"));
+
+                    if ix > 0 {
+                        output.push('\n');
+                    }
+                    output.push_str("```");
+                    output.push_str(&path);
+                    output.push('\n');
+                    for chunk in snapshot.as_rope().chunks() {
+                        output.push_str(chunk);
+                    }
+                    if !output.ends_with('\n') {
+                        output.push('\n');
+                    }
+                    output.push_str("```");
+                }
+                Ok(output)
+            })
+        });
+
+        SlashCommandInvocation {
+            output: output.unwrap_or_else(|error| Task::ready(Err(error))),
+            invalidated: invalidate_rx,
+            cleanup: SlashCommandCleanup::new(move || drop(subscriptions)),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use futures::channel::oneshot;
+use gpui::{AppContext, Task, WindowHandle};
+use language::LspAdapterDelegate;
+use terminal_view::{terminal_panel::TerminalPanel, TerminalView};
+use workspace::Workspace;
+
+use super::{SlashCommand, SlashCommandCleanup, SlashCommandInvocation};
+
+const DEFAULT_LINE_COUNT: usize = 50;
+
+pub(crate) struct TerminalSlashCommand {
+    workspace: WindowHandle<Workspace>,
+}
+
+impl TerminalSlashCommand {
+    pub fn new(workspace: WindowHandle<Workspace>) -> Self {
+        Self { workspace }
+    }
+}
+
+impl SlashCommand for TerminalSlashCommand {
+    fn name(&self) -> String {
+        "terminal".into()
+    }
+
+    fn description(&self) -> String {
+        "insert the active terminal's recent output".into()
+    }
+
+    fn complete_argument(
+        &self,
+        _query: String,
+        _argument_index: usize,
+        _cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        _cx: &mut AppContext,
+    ) -> Task<Result<Vec<String>>> {
+        Task::ready(Ok(Vec::new()))
+    }
+
+    fn requires_argument(&self) -> bool {
+        false
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[&str],
+        _delegate: Arc<dyn LspAdapterDelegate>,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        let line_count = match arguments.first() {
+            Some(argument) => argument
+                .parse::<usize>()
+                .with_context(|| format!("invalid line count {:?}", argument)),
+            None => Ok(DEFAULT_LINE_COUNT),
+        };
+
+        let output = line_count.and_then(|line_count| {
+            self.workspace.update(cx, |workspace, cx| {
+                let terminal_view = workspace
+                    .panel::<TerminalPanel>(cx)
+                    .and_then(|panel| panel.read(cx).pane().read(cx).active_item())
+                    .and_then(|item| item.downcast::<TerminalView>())
+                    .context("no active terminal")?;
+                let lines = terminal_view
+                    .read(cx)
+                    .model()
+                    .read(cx)
+                    .last_n_lines(line_count);
+                if lines.is_empty() {
+                    return Err(anyhow!("the terminal is empty"));
+                }
+
+                let mut output = String::new();
+                output.push_str("```console\n");
+                for line in lines {
+                    output.push_str(&line);
+                    output.push('\n');
+                }
+                output.push_str("```");
+                Ok(output)
+            })?
+        });
+
+        SlashCommandInvocation {
+            output: Task::ready(output),
+            invalidated: oneshot::channel().1,
+            cleanup: SlashCommandCleanup::default(),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use anyhow::Result;
-use futures::channel::oneshot;
+use futures::channel::{mpsc, oneshot};
 use gpui::{AppContext, Task};
 use language::LspAdapterDelegate;
 
@@ -17,16 +17,19 @@ pub fn init(cx: &mut AppContext) {
 pub trait SlashCommand: 'static + Send + Sync {
     fn name(&self) -> String;
     fn description(&self) -> String;
+    /// Returns completions for the argument at `argument_index`, given the
+    /// partially-typed `query` for that argument.
     fn complete_argument(
         &self,
         query: String,
+        argument_index: usize,
         cancel: Arc<AtomicBool>,
         cx: &mut AppContext,
     ) -> Task<Result<Vec<String>>>;
     fn requires_argument(&self) -> bool;
     fn run(
         self: Arc<Self>,
-        argument: Option<&str>,
+        arguments: &[&str],
         // TODO: We're just using the `LspAdapterDelegate` here because that is
         // what the extension API is already expecting.
         //
@@ -35,14 +38,53 @@ pub trait SlashCommand: 'static + Send + Sync {
         delegate: Arc<dyn LspAdapterDelegate>,
         cx: &mut AppContext,
     ) -> SlashCommandInvocation;
+    /// Runs the command, reporting progress through `progress` while its
+    /// output is being produced.
+    ///
+    /// Commands that finish quickly don't need to override this.
+    fn run_with_progress(
+        self: Arc<Self>,
+        arguments: &[&str],
+        delegate: Arc<dyn LspAdapterDelegate>,
+        _progress: SlashCommandProgress,
+        cx: &mut AppContext,
+    ) -> SlashCommandInvocation {
+        self.run(arguments, delegate, cx)
+    }
 }
 
 pub struct SlashCommandInvocation {
     pub output: Task<Result<String>>,
-    pub invalidated: oneshot::Receiver<()>,
+    pub invalidated: oneshot::Receiver<SlashCommandInvalidation>,
     pub cleanup: SlashCommandCleanup,
 }
 
+/// Signals that a [`SlashCommandInvocation`]'s output is out of date, and
+/// that the command needs to run again.
+#[derive(Default)]
+pub struct SlashCommandInvalidation {
+    /// The command to run again in place of the original one.
+    ///
+    /// This lets a command whose output depends on state captured when it
+    /// first ran, rather than on its arguments, carry that state over.
+    pub rerun_with: Option<Arc<dyn SlashCommand>>,
+}
+
+/// Reports the progress of a running [`SlashCommand`].
+#[derive(Clone)]
+pub struct SlashCommandProgress(mpsc::UnboundedSender<String>);
+
+impl SlashCommandProgress {
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<String>) {
+        let (tx, rx) = mpsc::unbounded();
+        (Self(tx), rx)
+    }
+
+    pub fn report(&self, message: impl Into<String>) {
+        self.0.unbounded_send(message.into()).ok();
+    }
+}
+
 #[derive(Default)]
 pub struct SlashCommandCleanup(Option<Box<dyn FnOnce()>>);
 
@@ -1,3 +1,4 @@
+use std::any::TypeId;
 use std::sync::Arc;
 
 use collections::HashMap;
@@ -15,7 +16,28 @@ impl Global for GlobalSlashCommandRegistry {}
 
 #[derive(Default)]
 struct SlashCommandRegistryState {
-    commands: HashMap<Arc<str>, Arc<dyn SlashCommand>>,
+    commands: HashMap<Arc<str>, RegisteredSlashCommand>,
+    aliases: HashMap<Arc<str>, Arc<str>>,
+}
+
+impl SlashCommandRegistryState {
+    fn remove_command(&mut self, name: &str) {
+        self.commands.remove(name);
+        self.aliases
+            .retain(|_, command_name| command_name.as_ref() != name);
+    }
+}
+
+struct RegisteredSlashCommand {
+    command: Arc<dyn SlashCommand>,
+    /// The type of the command, used to tell a command being re-registered
+    /// apart from a different command with the same name.
+    type_id: TypeId,
+    /// The ID of the extension that provides the command, if any.
+    ///
+    /// All extension commands share a type, so this is what tells commands
+    /// from different extensions apart.
+    extension_id: Option<Arc<str>>,
 }
 
 #[derive(Default)]
@@ -40,25 +62,241 @@ impl SlashCommandRegistry {
         Arc::new(Self {
             state: RwLock::new(SlashCommandRegistryState {
                 commands: HashMap::default(),
+                aliases: HashMap::default(),
             }),
         })
     }
 
     /// Registers the provided [`SlashCommand`].
-    pub fn register_command(&self, command: impl SlashCommand) {
-        self.state
-            .write()
-            .commands
-            .insert(command.name().into(), Arc::new(command));
+    ///
+    /// Replaces any command already registered with the same name, logging a
+    /// warning if that command is a different kind of command.
+    pub fn register_command<T: SlashCommand>(&self, command: T) {
+        self.insert_command(Arc::new(command), TypeId::of::<T>(), None);
+    }
+
+    /// Registers the provided [`SlashCommand`] on behalf of the extension with
+    /// the given ID.
+    ///
+    /// Replaces any command already registered with the same name, logging a
+    /// warning if that command was provided by something else.
+    pub fn register_extension_command<T: SlashCommand>(
+        &self,
+        command: T,
+        extension_id: impl Into<Arc<str>>,
+    ) {
+        self.insert_command(
+            Arc::new(command),
+            TypeId::of::<T>(),
+            Some(extension_id.into()),
+        );
+    }
+
+    /// Inserts the command, returning whether it replaced a different command
+    /// with the same name.
+    fn insert_command(
+        &self,
+        command: Arc<dyn SlashCommand>,
+        type_id: TypeId,
+        extension_id: Option<Arc<str>>,
+    ) -> bool {
+        let name: Arc<str> = command.name().into();
+        let previous = self.state.write().commands.insert(
+            name.clone(),
+            RegisteredSlashCommand {
+                command,
+                type_id,
+                extension_id: extension_id.clone(),
+            },
+        );
+        let replaced_other = previous.map_or(false, |previous| {
+            previous.type_id != type_id || previous.extension_id != extension_id
+        });
+        if replaced_other {
+            log::warn!(
+                "slash command {:?} was registered more than once; the previous command was replaced",
+                name
+            );
+        }
+        replaced_other
+    }
+
+    /// Unregisters the [`SlashCommand`] with the given name, along with any
+    /// aliases that refer to it.
+    pub fn unregister_command(&self, name: &str) {
+        self.state.write().remove_command(name);
+    }
+
+    /// Unregisters the [`SlashCommand`] with the given name, along with any
+    /// aliases that refer to it, if it was registered by the extension with
+    /// the given ID.
+    ///
+    /// A command that has since been replaced, such as a built-in command
+    /// with the same name, is left in place.
+    pub fn unregister_extension_command(&self, name: &str, extension_id: &str) {
+        let mut state = self.state.write();
+        let is_registered_by_extension = state.commands.get(name).map_or(false, |registered| {
+            registered.extension_id.as_deref() == Some(extension_id)
+        });
+        if is_registered_by_extension {
+            state.remove_command(name);
+        }
+    }
+
+    /// Registers `alias` as an alternative name for the [`SlashCommand`] named `command_name`.
+    pub fn register_alias(&self, alias: impl Into<Arc<str>>, command_name: impl Into<Arc<str>>) {
+        let alias = alias.into();
+        let mut state = self.state.write();
+        if state.commands.contains_key(&alias) {
+            log::warn!(
+                "slash command alias {:?} is shadowed by a command with the same name",
+                alias
+            );
+        }
+        state.aliases.insert(alias, command_name.into());
     }
 
     /// Returns the names of registered [`SlashCommand`]s.
+    ///
+    /// Aliases are not included.
     pub fn command_names(&self) -> Vec<Arc<str>> {
         self.state.read().commands.keys().cloned().collect()
     }
 
-    /// Returns the [`SlashCommand`] with the given name.
+    /// Returns the registered aliases, along with the names of the [`SlashCommand`]s they refer to.
+    pub fn aliases(&self) -> Vec<(Arc<str>, Arc<str>)> {
+        self.state
+            .read()
+            .aliases
+            .iter()
+            .map(|(alias, name)| (alias.clone(), name.clone()))
+            .collect()
+    }
+
+    /// Returns the [`SlashCommand`] with the given name or alias.
     pub fn command(&self, name: &str) -> Option<Arc<dyn SlashCommand>> {
-        self.state.read().commands.get(name).cloned()
+        let state = self.state.read();
+        let registered = match state.commands.get(name) {
+            Some(registered) => registered,
+            None => state.commands.get(state.aliases.get(name)?)?,
+        };
+        Some(registered.command.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use anyhow::Result;
+    use futures::channel::oneshot;
+    use gpui::Task;
+    use language::LspAdapterDelegate;
+
+    use super::*;
+    use crate::{SlashCommandCleanup, SlashCommandInvocation};
+
+    #[test]
+    fn test_aliases() {
+        let registry = SlashCommandRegistry::new();
+        registry.register_command(TestCommand("file"));
+        registry.register_alias("f", "file");
+
+        assert_eq!(registry.command("f").unwrap().name(), "file");
+        assert!(registry.command("g").is_none());
+        assert_eq!(registry.command_names(), vec![Arc::from("file")]);
+        assert_eq!(
+            registry.aliases(),
+            vec![(Arc::from("f"), Arc::from("file"))]
+        );
+
+        registry.unregister_command("file");
+        assert!(registry.command("file").is_none());
+        assert!(registry.command("f").is_none());
+        assert!(registry.aliases().is_empty());
+    }
+
+    #[test]
+    fn test_collisions() {
+        let registry = SlashCommandRegistry::new();
+        let insert = |command: Arc<dyn SlashCommand>, type_id, extension_id: Option<&str>| {
+            registry.insert_command(command, type_id, extension_id.map(Arc::from))
+        };
+        let test_command = |name| Arc::new(TestCommand(name)) as Arc<dyn SlashCommand>;
+        let other_command = |name| Arc::new(OtherTestCommand(name)) as Arc<dyn SlashCommand>;
+        let test_type = TypeId::of::<TestCommand>();
+        let other_type = TypeId::of::<OtherTestCommand>();
+
+        // Re-registering the same command isn't a collision.
+        assert!(!insert(test_command("file"), test_type, None));
+        assert!(!insert(test_command("file"), test_type, None));
+
+        // Replacing it with a different type of command is.
+        assert!(insert(other_command("file"), other_type, None));
+
+        // Commands from different extensions collide, even though they have
+        // the same type.
+        assert!(!insert(test_command("docs"), test_type, Some("ext-a")));
+        assert!(!insert(test_command("docs"), test_type, Some("ext-a")));
+        assert!(insert(test_command("docs"), test_type, Some("ext-b")));
+
+        // Unloading an extension only removes commands it still owns.
+        registry.unregister_extension_command("docs", "ext-a");
+        assert!(registry.command("docs").is_some());
+        registry.unregister_extension_command("docs", "ext-b");
+        assert!(registry.command("docs").is_none());
+
+        insert(test_command("file"), test_type, Some("ext-a"));
+        insert(other_command("file"), other_type, None);
+        registry.unregister_extension_command("file", "ext-a");
+        assert!(registry.command("file").is_some());
+    }
+
+    struct TestCommand(&'static str);
+
+    struct OtherTestCommand(&'static str);
+
+    macro_rules! impl_test_command {
+        ($command:ty) => {
+            impl SlashCommand for $command {
+                fn name(&self) -> String {
+                    self.0.into()
+                }
+
+                fn description(&self) -> String {
+                    String::new()
+                }
+
+                fn complete_argument(
+                    &self,
+                    _query: String,
+                    _argument_index: usize,
+                    _cancel: Arc<AtomicBool>,
+                    _cx: &mut AppContext,
+                ) -> Task<Result<Vec<String>>> {
+                    Task::ready(Ok(Vec::new()))
+                }
+
+                fn requires_argument(&self) -> bool {
+                    false
+                }
+
+                fn run(
+                    self: Arc<Self>,
+                    _arguments: &[&str],
+                    _delegate: Arc<dyn LspAdapterDelegate>,
+                    _cx: &mut AppContext,
+                ) -> SlashCommandInvocation {
+                    SlashCommandInvocation {
+                        output: Task::ready(Ok(String::new())),
+                        invalidated: oneshot::channel().1,
+                        cleanup: SlashCommandCleanup::default(),
+                    }
+                }
+            }
+        };
+    }
+
+    impl_test_command!(TestCommand);
+    impl_test_command!(OtherTestCommand);
+}
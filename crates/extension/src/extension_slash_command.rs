@@ -34,6 +34,7 @@ impl SlashCommand for ExtensionSlashCommand {
     fn complete_argument(
         &self,
         _query: String,
+        _argument_index: usize,
         _cancel: Arc<AtomicBool>,
         _cx: &mut AppContext,
     ) -> Task<Result<Vec<String>>> {
@@ -42,11 +43,13 @@ impl SlashCommand for ExtensionSlashCommand {
 
     fn run(
         self: Arc<Self>,
-        argument: Option<&str>,
+        arguments: &[&str],
         delegate: Arc<dyn LspAdapterDelegate>,
         cx: &mut AppContext,
     ) -> SlashCommandInvocation {
-        let argument = argument.map(|arg| arg.to_string());
+        // Extensions receive a single argument, so we pass them the arguments
+        // joined back together.
+        let argument = (!arguments.is_empty()).then(|| arguments.join(" "));
 
         let output = cx.background_executor().spawn(async move {
             let output = self
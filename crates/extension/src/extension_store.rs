@@ -1017,6 +1017,10 @@ impl ExtensionStore {
                 continue;
             };
             grammars_to_remove.extend(extension.manifest.grammars.keys().cloned());
+            for slash_command_name in extension.manifest.slash_commands.keys() {
+                self.slash_command_registry
+                    .unregister_extension_command(slash_command_name, extension_id);
+            }
             for (language_server_name, config) in extension.manifest.language_servers.iter() {
                 for language in config.languages() {
                     self.language_registry
@@ -1178,8 +1182,8 @@ impl ExtensionStore {
                     }
 
                     for (slash_command_name, slash_command) in &manifest.slash_commands {
-                        this.slash_command_registry
-                            .register_command(ExtensionSlashCommand {
+                        this.slash_command_registry.register_extension_command(
+                            ExtensionSlashCommand {
                                 command: crate::wit::SlashCommand {
                                     name: slash_command_name.to_string(),
                                     description: slash_command.description.to_string(),
@@ -1187,7 +1191,9 @@ impl ExtensionStore {
                                 },
                                 extension: wasm_extension.clone(),
                                 host: this.wasm_host.clone(),
-                            });
+                            },
+                            manifest.id.clone(),
+                        );
                     }
                 }
                 this.wasm_extensions.extend(wasm_extensions);
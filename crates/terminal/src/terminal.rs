@@ -933,6 +933,36 @@ impl Terminal {
         &self.last_content
     }
 
+    /// Returns the last `n` lines of the terminal, including its scrollback,
+    /// from oldest to newest.
+    ///
+    /// Empty rows at the bottom of the grid, below the last output, are
+    /// skipped. Blank lines within the output are kept.
+    pub fn last_n_lines(&self, n: usize) -> Vec<String> {
+        let term = self.term.clone();
+        let terminal = term.lock_unfair();
+
+        let mut lines = Vec::new();
+        let mut line = terminal.bottommost_line();
+        while lines.len() < n {
+            let text = terminal.grid()[line][..Column(terminal.grid().columns())]
+                .iter()
+                .map(|cell| cell.c)
+                .collect::<String>();
+            let text = text.trim_end();
+            if !lines.is_empty() || !text.is_empty() {
+                lines.push(text.to_string());
+            }
+
+            if line == terminal.topmost_line() {
+                break;
+            }
+            line = Line(line.0 - 1);
+        }
+        lines.reverse();
+        lines
+    }
+
     //To test:
     //- Activate match on terminal (scrolling and selection)
     //- Editor search snapping behavior
@@ -1,66 +1,98 @@
-use zed::lsp::{Symbol, SymbolKind};
-use zed::{CodeLabel, CodeLabelSpan};
+mod language_servers;
+
+use zed::lsp::{Completion, Symbol};
+use zed::{serde_json, CodeLabel, LanguageServerId};
 use zed_extension_api::{self as zed, Result};
 
-struct HaskellExtension;
+use crate::language_servers::{Hls, StaticLs};
+
+struct HaskellExtension {
+    hls: Option<Hls>,
+    static_ls: Option<StaticLs>,
+}
 
 impl zed::Extension for HaskellExtension {
     fn new() -> Self {
-        Self
+        Self {
+            hls: None,
+            static_ls: None,
+        }
     }
 
     fn language_server_command(
         &mut self,
-        _language_server_id: &zed::LanguageServerId,
+        language_server_id: &LanguageServerId,
         worktree: &zed::Worktree,
     ) -> Result<zed::Command> {
-        let path = worktree
-            .which("haskell-language-server-wrapper")
-            .ok_or_else(|| "hls must be installed via ghcup".to_string())?;
+        // Zed only asks for the servers enabled in the Haskell
+        // `language_servers` setting. HLS is enabled by default, and static-ls
+        // only runs when the user opts into it, so that we don't start two
+        // servers for the same project.
+        match language_server_id.as_ref() {
+            Hls::LANGUAGE_SERVER_ID => {
+                let hls = self.hls.get_or_insert_with(|| Hls::new());
+                hls.language_server_command(worktree)
+            }
+            StaticLs::LANGUAGE_SERVER_ID => {
+                let static_ls = self.static_ls.get_or_insert_with(|| StaticLs::new());
+                static_ls.language_server_command(worktree)
+            }
+            language_server_id => Err(format!("unknown language server: {language_server_id}")),
+        }
+    }
 
-        Ok(zed::Command {
-            command: path,
-            args: vec!["lsp".to_string()],
-            env: Default::default(),
-        })
+    fn language_server_initialization_options(
+        &mut self,
+        language_server_id: &LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<serde_json::Value>> {
+        match language_server_id.as_ref() {
+            Hls::LANGUAGE_SERVER_ID => {
+                let hls = self.hls.get_or_insert_with(|| Hls::new());
+                hls.language_server_initialization_options(worktree)
+            }
+            _ => Ok(None),
+        }
     }
 
-    fn label_for_symbol(
+    fn language_server_workspace_configuration(
+        &mut self,
+        language_server_id: &LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<serde_json::Value>> {
+        match language_server_id.as_ref() {
+            Hls::LANGUAGE_SERVER_ID => {
+                let hls = self.hls.get_or_insert_with(|| Hls::new());
+                hls.language_server_workspace_configuration(worktree)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn label_for_completion(
         &self,
-        _language_server_id: &zed::LanguageServerId,
-        symbol: Symbol,
+        language_server_id: &LanguageServerId,
+        completion: Completion,
     ) -> Option<CodeLabel> {
-        let name = &symbol.name;
-
-        let (code, display_range, filter_range) = match symbol.kind {
-            SymbolKind::Struct => {
-                let data_decl = "data ";
-                let code = format!("{data_decl}{name} = A");
-                let display_range = 0..data_decl.len() + name.len();
-                let filter_range = data_decl.len()..display_range.end;
-                (code, display_range, filter_range)
-            }
-            SymbolKind::Constructor => {
-                let data_decl = "data A = ";
-                let code = format!("{data_decl}{name}");
-                let display_range = data_decl.len()..data_decl.len() + name.len();
-                let filter_range = 0..name.len();
-                (code, display_range, filter_range)
+        match language_server_id.as_ref() {
+            Hls::LANGUAGE_SERVER_ID => self.hls.as_ref()?.label_for_completion(completion),
+            StaticLs::LANGUAGE_SERVER_ID => {
+                self.static_ls.as_ref()?.label_for_completion(completion)
             }
-            SymbolKind::Variable => {
-                let code = format!("{name} :: T");
-                let display_range = 0..name.len();
-                let filter_range = 0..name.len();
-                (code, display_range, filter_range)
-            }
-            _ => return None,
-        };
+            _ => None,
+        }
+    }
 
-        Some(CodeLabel {
-            spans: vec![CodeLabelSpan::code_range(display_range)],
-            filter_range: filter_range.into(),
-            code,
-        })
+    fn label_for_symbol(
+        &self,
+        language_server_id: &LanguageServerId,
+        symbol: Symbol,
+    ) -> Option<CodeLabel> {
+        match language_server_id.as_ref() {
+            Hls::LANGUAGE_SERVER_ID => self.hls.as_ref()?.label_for_symbol(symbol),
+            StaticLs::LANGUAGE_SERVER_ID => self.static_ls.as_ref()?.label_for_symbol(symbol),
+            _ => None,
+        }
     }
 }
 
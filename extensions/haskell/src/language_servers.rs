@@ -0,0 +1,238 @@
+mod hls;
+mod static_ls;
+
+use zed::lsp::{Completion, CompletionKind, Symbol, SymbolKind};
+use zed::{CodeLabel, CodeLabelSpan};
+use zed_extension_api as zed;
+
+pub use hls::*;
+pub use static_ls::*;
+
+pub fn haskell_completion_label(completion: &Completion) -> Option<CodeLabel> {
+    let name = &completion.label;
+
+    match completion.kind? {
+        CompletionKind::Function
+        | CompletionKind::Method
+        | CompletionKind::Variable
+        | CompletionKind::Field
+        | CompletionKind::Constant => {
+            // Operators have to be parenthesized to appear in a type signature,
+            // but the parentheses aren't part of what the user types.
+            let (name, filter_range) = if name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            {
+                (name.clone(), 0..name.len())
+            } else {
+                (format!("({name})"), 1..name.len() + 1)
+            };
+            // HLS reports the type signature, with or without the leading `::`.
+            let ty = completion
+                .detail
+                .as_deref()
+                .map(|detail| {
+                    let detail = detail.trim_start();
+                    let detail = detail.strip_prefix("::").unwrap_or(detail);
+                    detail.split_whitespace().collect::<Vec<_>>().join(" ")
+                })
+                .filter(|ty| !ty.is_empty());
+
+            let (code, display_range) = match ty {
+                Some(ty) => {
+                    let code = format!("{name} :: {ty}");
+                    let display_range = 0..code.len();
+                    (code, display_range)
+                }
+                None => (format!("{name} :: T"), 0..name.len()),
+            };
+
+            Some(CodeLabel {
+                spans: vec![CodeLabelSpan::code_range(display_range)],
+                filter_range: filter_range.into(),
+                code,
+            })
+        }
+        CompletionKind::Class => {
+            let class_decl = "class ";
+            let code = format!("{class_decl}{name} a where");
+            let display_range = 0..class_decl.len() + name.len();
+
+            Some(CodeLabel {
+                spans: vec![CodeLabelSpan::code_range(display_range.clone())],
+                filter_range: (class_decl.len()..display_range.end).into(),
+                code,
+            })
+        }
+        CompletionKind::Constructor => {
+            let data_decl = "data A = ";
+            let code = format!("{data_decl}{name}");
+
+            Some(CodeLabel {
+                spans: vec![CodeLabelSpan::code_range(
+                    data_decl.len()..data_decl.len() + name.len(),
+                )],
+                filter_range: (0..name.len()).into(),
+                code,
+            })
+        }
+        CompletionKind::Struct | CompletionKind::Interface | CompletionKind::Enum => {
+            let data_decl = "data ";
+            let code = format!("{data_decl}{name} = A");
+
+            Some(CodeLabel {
+                spans: vec![CodeLabelSpan::code_range(
+                    data_decl.len()..data_decl.len() + name.len(),
+                )],
+                filter_range: (0..name.len()).into(),
+                code,
+            })
+        }
+        _ => None,
+    }
+}
+
+pub fn haskell_symbol_label(symbol: &Symbol) -> Option<CodeLabel> {
+    let name = &symbol.name;
+
+    let (code, display_range, filter_range) = match symbol.kind {
+        SymbolKind::Module => {
+            let module_decl = "module ";
+            let code = format!("{module_decl}{name} where");
+            let display_range = 0..module_decl.len() + name.len();
+            let filter_range = module_decl.len()..display_range.end;
+            (code, display_range, filter_range)
+        }
+        SymbolKind::Struct => {
+            let data_decl = "data ";
+            let code = format!("{data_decl}{name} = A");
+            let display_range = 0..data_decl.len() + name.len();
+            let filter_range = data_decl.len()..display_range.end;
+            (code, display_range, filter_range)
+        }
+        SymbolKind::Class => {
+            let class_decl = "class ";
+            let code = format!("{class_decl}{name} a where");
+            let display_range = 0..class_decl.len() + name.len();
+            let filter_range = class_decl.len()..display_range.end;
+            (code, display_range, filter_range)
+        }
+        // GADTs, type families and the like.
+        SymbolKind::Interface | SymbolKind::Enum => {
+            let type_decl = "type ";
+            let code = format!("{type_decl}{name} = A");
+            let display_range = 0..type_decl.len() + name.len();
+            let filter_range = type_decl.len()..display_range.end;
+            (code, display_range, filter_range)
+        }
+        SymbolKind::Constructor => {
+            let data_decl = "data A = ";
+            let code = format!("{data_decl}{name}");
+            let display_range = data_decl.len()..data_decl.len() + name.len();
+            let filter_range = 0..name.len();
+            (code, display_range, filter_range)
+        }
+        SymbolKind::Field => {
+            let record_decl = "data A = A { ";
+            let code = format!("{record_decl}{name} :: T }}");
+            let display_range = record_decl.len()..record_decl.len() + name.len();
+            let filter_range = 0..name.len();
+            (code, display_range, filter_range)
+        }
+        SymbolKind::Variable | SymbolKind::Function | SymbolKind::Method => {
+            let code = format!("{name} :: T");
+            let display_range = 0..name.len();
+            let filter_range = 0..name.len();
+            (code, display_range, filter_range)
+        }
+        _ => return None,
+    };
+
+    Some(CodeLabel {
+        spans: vec![CodeLabelSpan::code_range(display_range)],
+        filter_range: filter_range.into(),
+        code,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use zed_extension_api::lsp::{Completion, CompletionKind, Symbol, SymbolKind};
+    use zed_extension_api::{CodeLabel, CodeLabelSpan};
+
+    use super::{haskell_completion_label, haskell_symbol_label};
+
+    fn label_text(label: &CodeLabel) -> String {
+        label
+            .spans
+            .iter()
+            .map(|span| match span {
+                CodeLabelSpan::CodeRange(range) => {
+                    label.code[range.start as usize..range.end as usize].to_string()
+                }
+                CodeLabelSpan::Literal(literal) => literal.text.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_function_completion_label() {
+        let completion = |label: &str, detail: Option<&str>| Completion {
+            label: label.into(),
+            detail: detail.map(Into::into),
+            kind: Some(CompletionKind::Function),
+            insert_text_format: None,
+        };
+
+        let label = haskell_completion_label(&completion(
+            "mapM_",
+            Some(":: (a -> m b)\n  -> t a -> m ()"),
+        ))
+        .unwrap();
+        assert_eq!(label_text(&label), "mapM_ :: (a -> m b) -> t a -> m ()");
+        assert_eq!(label.filter_range.start..label.filter_range.end, 0..5);
+
+        let label =
+            haskell_completion_label(&completion("<$>", Some("(a -> b) -> f a -> f b"))).unwrap();
+        assert_eq!(label_text(&label), "(<$>) :: (a -> b) -> f a -> f b");
+        assert_eq!(label.filter_range.start..label.filter_range.end, 1..4);
+
+        let label = haskell_completion_label(&completion("foo", None)).unwrap();
+        assert_eq!(label_text(&label), "foo");
+    }
+
+    #[test]
+    fn test_class_completion_label() {
+        let label = haskell_completion_label(&Completion {
+            label: "Functor".into(),
+            detail: None,
+            kind: Some(CompletionKind::Class),
+            insert_text_format: None,
+        })
+        .unwrap();
+
+        assert_eq!(label.code, "class Functor a where");
+        assert_eq!(label_text(&label), "class Functor");
+        assert_eq!(label.filter_range.start..label.filter_range.end, 6..13);
+    }
+
+    #[test]
+    fn test_class_symbol_label() {
+        let label = haskell_symbol_label(&Symbol {
+            kind: SymbolKind::Class,
+            name: "Functor".into(),
+        })
+        .unwrap();
+
+        assert_eq!(label.code, "class Functor a where");
+        let CodeLabelSpan::CodeRange(display_range) = &label.spans[0] else {
+            panic!("expected a code range span");
+        };
+        assert_eq!(
+            &label.code[display_range.start as usize..display_range.end as usize],
+            "class Functor"
+        );
+        assert_eq!(
+            &label.code[label.filter_range.start as usize..label.filter_range.end as usize],
+            "Functor"
+        );
+    }
+}
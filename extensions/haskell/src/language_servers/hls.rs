@@ -0,0 +1,212 @@
+use std::path::{Path, PathBuf};
+
+use zed::lsp::{Completion, Symbol};
+use zed::settings::LspSettings;
+use zed::{serde_json, CodeLabel};
+use zed_extension_api::{self as zed, Result};
+
+use crate::language_servers::{haskell_completion_label, haskell_symbol_label};
+
+const WRAPPER: &str = "haskell-language-server-wrapper";
+
+pub struct Hls;
+
+impl Hls {
+    pub const LANGUAGE_SERVER_ID: &'static str = "hls";
+
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn language_server_command(&mut self, worktree: &zed::Worktree) -> Result<zed::Command> {
+        let binary_settings = LspSettings::for_worktree(Self::LANGUAGE_SERVER_ID, worktree)
+            .ok()
+            .and_then(|lsp_settings| lsp_settings.binary);
+        let binary_path = binary_settings
+            .as_ref()
+            .and_then(|binary_settings| binary_settings.path.clone());
+        let binary_args = binary_settings.and_then(|binary_settings| binary_settings.arguments);
+
+        let path = binary_path
+            .or_else(|| Self::binary_path(worktree))
+            .ok_or_else(|| "hls must be installed via ghcup".to_string())?;
+
+        // Configured arguments, such as `--debug` or `--logfile`, go after the
+        // `lsp` subcommand.
+        let mut args = vec!["lsp".to_string()];
+        args.extend(binary_args.unwrap_or_default());
+
+        Ok(zed::Command {
+            command: path,
+            args,
+            env: Default::default(),
+        })
+    }
+
+    fn binary_path(worktree: &zed::Worktree) -> Option<String> {
+        // The wrapper picks the HLS build matching the project's GHC, so
+        // prefer it. Otherwise look for an HLS built for the project's GHC.
+        let binary_names = std::iter::once(WRAPPER.to_string()).chain(
+            Self::project_ghc_version(worktree)
+                .map(|version| format!("haskell-language-server-{version}")),
+        );
+
+        // ghcup installs HLS into its own `bin` directory, which isn't always
+        // on the worktree's `PATH`. `which` also accepts a path, so use it to
+        // check whether the binary exists in any of ghcup's directories.
+        let ghcup_bin_dirs = Self::ghcup_bin_dirs(worktree);
+        for binary_name in binary_names {
+            if let Some(path) = worktree.which(&binary_name) {
+                return Some(path);
+            }
+            if let Some(path) = ghcup_bin_dirs
+                .iter()
+                .find_map(|dir| worktree.which(&dir.join(&binary_name).to_string_lossy()))
+            {
+                return Some(path);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the GHC version pinned by the project's `stack.yaml` or
+    /// `cabal.project`, if any.
+    fn project_ghc_version(worktree: &zed::Worktree) -> Option<String> {
+        if let Ok(stack_yaml) = worktree.read_text_file("stack.yaml") {
+            if let Some(version) = ghc_version_from_stack_yaml(&stack_yaml) {
+                return Some(version);
+            }
+        }
+        let cabal_project = worktree.read_text_file("cabal.project").ok()?;
+        ghc_version_from_cabal_project(&cabal_project)
+    }
+
+    /// Returns the directories ghcup may have installed binaries into.
+    fn ghcup_bin_dirs(worktree: &zed::Worktree) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+
+        // `ghcup` itself lives alongside the binaries it installs.
+        if let Some(ghcup) = worktree.which("ghcup") {
+            dirs.extend(Path::new(&ghcup).parent().map(Path::to_path_buf));
+        }
+
+        let env = worktree.shell_env();
+        let env_var = |name: &str| {
+            env.iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+        if let Some(base) = env_var("GHCUP_INSTALL_BASE_PREFIX").or_else(|| env_var("HOME")) {
+            dirs.push(Path::new(&base).join(".ghcup").join("bin"));
+        }
+        let (platform, _) = zed::current_platform();
+        if matches!(platform, zed::Os::Windows) {
+            dirs.push(PathBuf::from("C:\\ghcup\\bin"));
+        }
+
+        dirs
+    }
+
+    pub fn language_server_initialization_options(
+        &mut self,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<serde_json::Value>> {
+        Ok(
+            LspSettings::for_worktree(Self::LANGUAGE_SERVER_ID, worktree)
+                .ok()
+                .and_then(|lsp_settings| lsp_settings.initialization_options),
+        )
+    }
+
+    /// HLS reads its configuration, such as `formattingProvider` and the
+    /// `plugin` toggles, from the `haskell` section.
+    pub fn language_server_workspace_configuration(
+        &mut self,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<serde_json::Value>> {
+        let settings = LspSettings::for_worktree(Self::LANGUAGE_SERVER_ID, worktree)
+            .ok()
+            .and_then(|lsp_settings| lsp_settings.settings)
+            .unwrap_or_default();
+
+        Ok(Some(serde_json::json!({
+            "haskell": settings
+        })))
+    }
+
+    pub fn label_for_completion(&self, completion: Completion) -> Option<CodeLabel> {
+        haskell_completion_label(&completion)
+    }
+
+    pub fn label_for_symbol(&self, symbol: Symbol) -> Option<CodeLabel> {
+        haskell_symbol_label(&symbol)
+    }
+}
+
+/// Reads the GHC version from a `stack.yaml`, which pins it either with an
+/// explicit `compiler` or with a `ghc-X.Y.Z` resolver.
+///
+/// Stackage LTS and nightly resolvers also imply a GHC version, but mapping
+/// them would require a snapshot lookup, so they're not handled.
+fn ghc_version_from_stack_yaml(contents: &str) -> Option<String> {
+    let value = |key: &str| {
+        contents.lines().find_map(|line| {
+            let value = line.strip_prefix(key)?.trim_start().strip_prefix(':')?;
+            ghc_version(value)
+        })
+    };
+    value("compiler")
+        .or_else(|| value("resolver"))
+        .or_else(|| value("snapshot"))
+}
+
+/// Reads the GHC version from a `cabal.project`'s `with-compiler` field.
+fn ghc_version_from_cabal_project(contents: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let value = line
+            .trim_start()
+            .strip_prefix("with-compiler")?
+            .trim_start()
+            .strip_prefix(':')?;
+        ghc_version(value)
+    })
+}
+
+fn ghc_version(value: &str) -> Option<String> {
+    let value = value
+        .split('#')
+        .next()?
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'');
+    let version = value.strip_prefix("ghc-")?;
+    if !version.is_empty() && version.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        Some(version.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ghc_version_from_cabal_project, ghc_version_from_stack_yaml};
+
+    #[test]
+    fn test_project_ghc_version() {
+        assert_eq!(
+            ghc_version_from_stack_yaml("resolver: lts-22.7\ncompiler: ghc-9.6.4 # pinned\n"),
+            Some("9.6.4".to_string())
+        );
+        assert_eq!(
+            ghc_version_from_stack_yaml("resolver: ghc-9.4.8\npackages:\n- .\n"),
+            Some("9.4.8".to_string())
+        );
+        assert_eq!(ghc_version_from_stack_yaml("resolver: lts-22.7\n"), None);
+
+        assert_eq!(
+            ghc_version_from_cabal_project("packages: .\n  with-compiler: ghc-9.8.1\n"),
+            Some("9.8.1".to_string())
+        );
+        assert_eq!(ghc_version_from_cabal_project("with-compiler: ghc\n"), None);
+    }
+}
@@ -0,0 +1,44 @@
+use zed::lsp::{Completion, Symbol};
+use zed::settings::LspSettings;
+use zed::CodeLabel;
+use zed_extension_api::{self as zed, Result};
+
+use crate::language_servers::{haskell_completion_label, haskell_symbol_label};
+
+pub struct StaticLs;
+
+impl StaticLs {
+    pub const LANGUAGE_SERVER_ID: &'static str = "static-ls";
+
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn language_server_command(&mut self, worktree: &zed::Worktree) -> Result<zed::Command> {
+        let binary_settings = LspSettings::for_worktree(Self::LANGUAGE_SERVER_ID, worktree)
+            .ok()
+            .and_then(|lsp_settings| lsp_settings.binary);
+        let binary_path = binary_settings
+            .as_ref()
+            .and_then(|binary_settings| binary_settings.path.clone());
+        let binary_args = binary_settings.and_then(|binary_settings| binary_settings.arguments);
+
+        let path = binary_path
+            .or_else(|| worktree.which("static-ls"))
+            .ok_or_else(|| "static-ls must be installed and available on your PATH".to_string())?;
+
+        Ok(zed::Command {
+            command: path,
+            args: binary_args.unwrap_or_default(),
+            env: Default::default(),
+        })
+    }
+
+    pub fn label_for_completion(&self, completion: Completion) -> Option<CodeLabel> {
+        haskell_completion_label(&completion)
+    }
+
+    pub fn label_for_symbol(&self, symbol: Symbol) -> Option<CodeLabel> {
+        haskell_symbol_label(&symbol)
+    }
+}